@@ -26,6 +26,12 @@
 //!
 //! The crate will be part of **galvanic**---a complete test framework for **Rust**.
 
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "serde")]
+extern crate serde_json;
+
+use std::any::Any;
 use std::fmt::{Debug, Display, Formatter, Result as FormatResult};
 
 /// States that the asserted value satisfies the required properties of the supplied `Matcher`.
@@ -39,6 +45,13 @@ use std::fmt::{Debug, Display, Formatter, Result as FormatResult};
 ///     assert_that!(EXPRESSION);
 ///     assert_that!(EXPRESSION, otherwise "some error message");
 ///     ```
+///
+///     Variables used in the expression can be captured by listing them before the expression.
+///     Their `Debug` representation is then included in the error message.
+///
+///     ```rust,ignore
+///     assert_that!(a, b => a < b);
+///     ```
 ///  2. Assert that some expression satifies the properties of some `Matcher`.
 ///     Expressions used with `Matcher`s **must return a reference** to a value.
 ///     The `Matcher` is either predefined, a user defined type with a `Matcher` implementation, or a closure returning a `MatchResult`.
@@ -56,8 +69,37 @@ use std::fmt::{Debug, Display, Formatter, Result as FormatResult};
 ///     assert_that!(panic!("panic"), panics);
 ///     assert_that!(1+1, does not panic);
 ///     ```
+///
+///     The payload of the panic can be bound to inspect it further.
+///     The payload's message can be extracted with `panic_message`.
+///
+///     ```rust,ignore
+///     assert_that!(panic!("panic"), panics as payload => {
+///         assert_that!(&panic_message(&*payload), eq(Some("panic")));
+///     });
+///     ```
+///
+///     An expression can also be evaluated repeatedly to assert that it always produces the same value.
+///
+///     ```rust,ignore
+///     assert_that!(hash("foo"), is deterministic(10));
+///     ```
 #[macro_export]
 macro_rules! assert_that {
+    ( $($capture: ident),+ => $actual: expr ) => {{
+        if !$actual {
+            let mut captures = String::new();
+            $( captures.push_str(&format!("\n        {} = {:?}", stringify!($capture), $capture)); )+
+            panic!("\nFailed assertion; '{}' is not true,\n    Where:{}", stringify!($actual), captures);
+        }
+    }};
+    ( $actual: expr, panics as $payload: ident => $body: block ) => {{
+        let result = ::std::panic::catch_unwind(|| { $actual; });
+        match result {
+            Ok(_) => panic!("\nFailed assertion; expected expression to panic"),
+            Err($payload) => $body
+        }
+    }};
     ( $actual: expr, panics ) => {{
         let result = ::std::panic::catch_unwind(|| { $actual; });
         if result.is_ok() {
@@ -70,6 +112,9 @@ macro_rules! assert_that {
             panic!("\nFailed assertion; expression panicked unexpectantly")
         }
     };
+    ( $actual: expr, is deterministic($times: expr) ) => {
+        assert_that!(&|| $actual, galvanic_assert::matchers::deterministic($times))
+    };
     ( $actual: expr) => {{
         if !$actual {
             panic!("\nFailed assertion; '{}' is not true", stringify!($actual));
@@ -113,6 +158,7 @@ macro_rules! assert_that {
 ///     ```rust,ignore
 ///     let e1 = get_expectation_for!(EXPRESSION);
 ///     let e2 = get_expectation_for!(EXPRESSION, otherwise "some error message");
+///     let e3 = get_expectation_for!(a, b => a < b);
 ///     ```
 ///  2. Expect that some expression satifies the properties of some `Matcher`.
 ///     Expressions used with `Matcher`s **must return a reference** to a value.
@@ -130,6 +176,7 @@ macro_rules! assert_that {
 ///     ```rust,ignore
 ///     let e1 = get_expectation_for!(panic!("panic"), panics);
 ///     let e2 = get_expectation_for!(&1+1, does not panic);
+///     let e3 = get_expectation_for!(1+1, is deterministic(10));
 ///     ```
 ///
 /// An expectation can be verfied manually
@@ -151,6 +198,17 @@ macro_rules! assert_that {
 /// ```
 #[macro_export]
 macro_rules! get_expectation_for {
+    ( $($capture: ident),+ => $actual: expr ) => {{
+        use galvanic_assert::Expectation;
+        if !$actual {
+            let mut captures = String::new();
+            $( captures.push_str(&format!("\n\t\t{} = {:?}", stringify!($capture), $capture)); )+
+            let assertion = format!("'{}' is true", stringify!($actual));
+            Expectation::failed(assertion, file!().to_string(), line!(),
+                                format!("'{}' is not true,\n\tWhere:{}", stringify!($actual), captures)
+            )
+        } else { Expectation::satisfied() }
+    }};
     ( $actual: expr, panics ) => {{
         use galvanic_assert::Expectation;
         let result = ::std::panic::catch_unwind(|| { $actual; });
@@ -173,6 +231,9 @@ macro_rules! get_expectation_for {
             )
         } else { Expectation::satisfied() }
     }};
+    ( $actual: expr, is deterministic($times: expr) ) => {
+        get_expectation_for!(&|| $actual, galvanic_assert::matchers::deterministic($times))
+    };
     ( $actual: expr) => {{
         use galvanic_assert::Expectation;
         if !$actual {
@@ -224,6 +285,7 @@ macro_rules! get_expectation_for {
 ///     ```rust,ignore
 ///     expect_that!(EXPRESSION);
 ///     expect_that!(EXPRESSION, otherwise "some error message");
+///     expect_that!(a, b => a < b);
 ///     ```
 ///  2. Expect that some expression satifies the properties of some `Matcher`.
 ///     Expressions used with `Matcher`s **must return a reference** to a value.
@@ -254,13 +316,36 @@ macro_rules! get_expectation_for {
 /// ```
 #[macro_export]
 macro_rules! expect_that {
+    ( $($capture: ident),+ => $actual: expr ) => { #[allow(unused_variables)] let expectation = get_expectation_for!($($capture),+ => $actual); };
     ( $actual: expr, panics ) => { #[allow(unused_variables)] let expectation = get_expectation_for!($actual, panics); };
     ( $actual: expr, does not panic ) => { #[allow(unused_variables)] let expectation = get_expectation_for!($actual, does not panic); };
+    ( $actual: expr, is deterministic($times: expr) ) => { #[allow(unused_variables)] let expectation = get_expectation_for!($actual, is deterministic($times)); };
     ( $actual: expr) => { #[allow(unused_variables)] let expectation = get_expectation_for!($actual); };
     ( $actual: expr , otherwise $reason: expr ) => { #[allow(unused_variables)] let expectation = get_expectation_for!($actual, otherwise $reason); };
     ( $actual: expr, $matcher: expr ) => { #[allow(unused_variables)] let expectation = get_expectation_for!($actual, $matcher); };
 }
 
+/// Extracts the message from the payload of a panic.
+///
+/// The payload can be obtained with `assert_that!(EXPRESSION, panics as payload => { ... })`.
+/// Returns `None` if the payload is neither a `&str` nor a `String`, e.g., if `std::panic::panic_any` was used.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::panic_message;
+/// use galvanic_assert::matchers::*;
+/// # fn main() {
+/// assert_that!(panic!("panicked at {}", 42), panics as payload => {
+///     assert_that!(&panic_message(&*payload), eq(Some("panicked at 42")));
+/// });
+/// # }
+pub fn panic_message(payload: &(Any + Send)) -> Option<&str> {
+    payload.downcast_ref::<&str>()
+           .map(|msg| *msg)
+           .or_else(|| payload.downcast_ref::<String>().map(|msg| msg.as_str()))
+}
+
 /// The trait which has to be implemented by all matchers.
 pub trait Matcher<T: ?Sized> {
     /// Checks the passed value if it satisfies the `Matcher`.
@@ -280,7 +365,7 @@ where F: Fn(&T) -> MatchResult {
 }
 
 /// The return type of any `Machter`
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub enum MatchResult {
     /// Indicates that the `Matcher` matched the value under inspection.
     Matched {
@@ -391,6 +476,21 @@ impl MatchResultBuilder {
         }
     }
 
+    /// Finalzes the builder depending on the given `Result`.
+    ///
+    /// The `Matcher` matched if the `result` is `Ok` and failed otherwise.
+    /// The failure message contains the error and the type of the expected `Ok` value.
+    /// Using this with `for_()` allows to distinguish multiple `Result`-based matchers in failure messages.
+    pub fn matched_if_ok<T, E: Debug>(self, result: Result<T,E>) -> MatchResult {
+        match result {
+            Ok(..) => self.matched(),
+            Err(err) => {
+                let reason = format!("expected Ok({}) but got Err({:?})", std::any::type_name::<T>(), err);
+                self.failed_because(&reason)
+            }
+        }
+    }
+
     /// Finalzes the builder indicating that the `Matcher` failed to the inspected value.
     ///
     /// The `actual` and `expected` value are used the generate a useful error message.
@@ -509,6 +609,20 @@ mod test_matchresult_conversions {
         }
     }
 
+    #[test]
+    fn should_label_result() {
+        let failed = MatchResultBuilder::for_("my_matcher").matched_if_ok(Err("error") as Result<u32,&str>);
+
+        match failed {
+            MatchResult::Failed { name, reason } => {
+                assert_eq!(name, "my_matcher");
+                assert!(reason.contains("Ok(u32)"));
+                assert!(reason.contains("Err(\"error\")"));
+            },
+            MatchResult::Matched {..} => panic!("`Err` should convert to into a `Failed` variant")
+        }
+    }
+
     #[test]
     fn should_convert_from_result() {
         let matched: MatchResult = (Ok(()) as Result<(),()>).into();
@@ -15,8 +15,12 @@
 
 //! The collection module contains matchers for asserting properties of collections and iterators.
 use super::super::*;
-use std::cmp::Ordering;
-use std::fmt::Debug;
+use std::cell::RefCell;
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BTreeMap, HashMap};
+use std::collections::hash_map::Entry;
+use std::hash::Hash;
+use std::fmt::{Debug, Display};
 use std::iter::FromIterator;
 
 /// Matches if the asserted collection contains *all and only* the expected elements in any order.
@@ -81,6 +85,93 @@ where T: PartialEq + Debug,
     }
 }
 
+/// Tries to assign `matcher` to an element using an augmenting path (Kuhn's algorithm).
+///
+/// `assignment` maps each element index to the matcher index which it is currently assigned to.
+fn assign_matcher(matcher: usize, adjacency: &[Vec<bool>], visited: &mut [bool], assignment: &mut [Option<usize>]) -> bool {
+    for element in 0..assignment.len() {
+        if adjacency[matcher][element] && !visited[element] {
+            visited[element] = true;
+            let is_free = match assignment[element] {
+                None => true,
+                Some(other) => assign_matcher(other, adjacency, visited, assignment)
+            };
+            if is_free {
+                assignment[element] = Some(matcher);
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Matches if the asserted collection contains *all and only* elements satisfying the given matchers in any order.
+///
+/// Each `Matcher` has to be satisfied by a distinct element and each element has to satisfy one of the matchers,
+/// i.e., a one-to-one assignment between elements and matchers has to exist.
+/// On failure the matchers which could not be assigned to an element and the elements left over are reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// assert_that!(&vec![1,5,3], contains_in_any_order_matching(vec![gt(4), lt(2), any_value()]));
+/// assert_that!(
+///     // no distinct element for the second `lt(2)`
+///     assert_that!(&vec![1,5,3], contains_in_any_order_matching(vec![gt(4), lt(2), lt(2)])),
+///     panics
+/// );
+/// # }
+pub fn contains_in_any_order_matching<'a, T: 'a, I: 'a>(matchers: Vec<Box<Matcher<T> + 'a>>) -> Box<Matcher<I> + 'a>
+where T: Debug,
+      for<'all> &'all I: IntoIterator<Item=&'all T> + Debug {
+    Box::new(move |actual: &I| {
+        let builder = MatchResultBuilder::for_("contains_in_any_order_matching");
+        let elements: Vec<_> = actual.into_iter().collect();
+
+        // the name of a matcher is only known after checking it against an element
+        let mut names = Vec::new();
+        let mut adjacency = Vec::new();
+        for matcher in matchers.iter() {
+            let mut name = None;
+            let row: Vec<bool> = elements.iter().map(|element| match matcher.check(element) {
+                MatchResult::Matched { name: n } => { name = Some(n); true },
+                MatchResult::Failed { name: n, .. } => { name = Some(n); false }
+            }).collect();
+            names.push(name);
+            adjacency.push(row);
+        }
+
+        let mut assignment = vec![None; elements.len()];
+        let unsatisfied: Vec<_> = (0..matchers.len()).filter(|&matcher| {
+            let mut visited = vec![false; elements.len()];
+            !assign_matcher(matcher, &adjacency, &mut visited, &mut assignment)
+        }).map(|matcher| match names[matcher] {
+            Some(ref name) => format!("#{} '{}'", matcher, name),
+            None => format!("#{}", matcher)
+        }).collect();
+        let unexpected: Vec<_> = elements.iter().zip(assignment.iter())
+                                         .filter(|&(_, assigned)| assigned.is_none())
+                                         .map(|(element, _)| element)
+                                         .collect();
+
+        let mut failed_msgs = Vec::new();
+        if !unsatisfied.is_empty() {
+            failed_msgs.push(format!("{:?} contains no distinct elements for the matchers: {}",
+                                     actual, unsatisfied.join(", ")));
+        }
+        if !unexpected.is_empty() {
+            failed_msgs.push(format!("{:?} contains elements not assigned to any matcher: {:?}",
+                                     actual, unexpected));
+        }
+
+        if failed_msgs.is_empty() { builder.matched() }
+        else { builder.failed_because(&failed_msgs.join("\n\t")) }
+    })
+}
+
 /// Matches if the asserted collection contains *all and only* of the expected elements in the given order.
 pub struct ContainsInOrder<T> {
     expected_elements: Vec<T>
@@ -148,6 +239,158 @@ where T: PartialEq + Debug,
     }
 }
 
+/// Matches if the asserted collection is a prefix of the expected sequence.
+pub struct IsPrefixOf<T> {
+    full_sequence: Vec<T>
+}
+
+/// Matches if the asserted collection is a prefix of the expected sequence.
+///
+/// The asserted collection must be equal to the first k elements of the `full_sequence`
+/// where k is the length of the asserted collection. An empty collection is a prefix of every sequence.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// assert_that!(&vec![1,2,3], is_prefix_of(vec![1,2,3,4,5,6]));
+/// assert_that!(
+///     assert_that!(&vec![1,2,4], is_prefix_of(vec![1,2,3,4,5,6])),
+///     panics
+/// );
+/// # }
+pub fn is_prefix_of<'a,T:'a,I:'a,J:'a>(full_sequence: I) -> Box<Matcher<J> + 'a>
+where T: PartialEq + Debug,
+      I: IntoIterator<Item=T>,
+      J: IntoIterator<Item=T>,
+      IsPrefixOf<T>: Matcher<J> {
+    Box::new(IsPrefixOf {
+        full_sequence: full_sequence.into_iter().collect()
+    })
+}
+
+impl<T, I> Matcher<I> for IsPrefixOf<T>
+where T: PartialEq + Debug,
+      for<'all> &'all I: IntoIterator<Item=&'all T> + Debug {
+    fn check(&self, actual: &I) -> MatchResult {
+        let builder = MatchResultBuilder::for_("is_prefix_of");
+        let actual_list: Vec<_> = actual.into_iter().collect();
+
+        if actual_list.len() > self.full_sequence.len() {
+            return builder.failed_because(
+                &format!("The actual list of length {} is longer than the expected sequence of length {}",
+                         actual_list.len(), self.full_sequence.len())
+            );
+        }
+
+        let mismatch = actual_list.iter()
+                                  .zip(self.full_sequence.iter())
+                                  .position(|(&act, exp)| act != exp);
+        match mismatch {
+            Some(idx) => builder.failed_because(
+                &format!("The actual list of length {} differs from the expected sequence at index {}: {:?} != {:?}",
+                         actual_list.len(), idx, actual_list[idx], self.full_sequence[idx])
+            ),
+            None => builder.matched()
+        }
+    }
+}
+
+/// Matches if the asserted collection begins with the elements of `prefix` in the same order.
+///
+/// The asserted collection may continue beyond the `prefix`. An empty `prefix` is satisfied by every collection.
+/// On failure the expected and the actual element at the first diverging index are reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// assert_that!(&vec!["HEADER", "v1", "row 1", "row 2"], starts_with_sequence(vec!["HEADER", "v1"]));
+/// # }
+pub fn starts_with_sequence<'a, T: 'a, I: 'a>(prefix: Vec<T>) -> Box<Matcher<I> + 'a>
+where T: PartialEq + Debug,
+      for<'all> &'all I: IntoIterator<Item=&'all T> {
+    Box::new(move |elements: &I| {
+        let builder = MatchResultBuilder::for_("starts_with_sequence");
+        let mut actual = elements.into_iter();
+        for (idx, expected) in prefix.iter().enumerate() {
+            match actual.next() {
+                Some(element) if element == expected => continue,
+                Some(element) => return builder.failed_because(
+                    &format!("the collection differs from the prefix at index {}: expected {:?} but found {:?}",
+                             idx, expected, element)
+                ),
+                None => return builder.failed_because(
+                    &format!("the collection ends at index {} but the prefix of length {} continues with {:?}",
+                             idx, prefix.len(), expected)
+                )
+            }
+        }
+        builder.matched()
+    })
+}
+
+/// Matches if the asserted collection is a suffix of the expected sequence.
+pub struct IsSuffixOf<T> {
+    full_sequence: Vec<T>
+}
+
+/// Matches if the asserted collection is a suffix of the expected sequence.
+///
+/// The asserted collection must be equal to the last k elements of the `full_sequence`
+/// where k is the length of the asserted collection. An empty collection is a suffix of every sequence.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// assert_that!(&vec![4,5,6], is_suffix_of(vec![1,2,3,4,5,6]));
+/// assert_that!(
+///     assert_that!(&vec![4,5], is_suffix_of(vec![1,2,3,4,5,6])),
+///     panics
+/// );
+/// # }
+pub fn is_suffix_of<'a,T:'a,I:'a,J:'a>(full_sequence: I) -> Box<Matcher<J> + 'a>
+where T: PartialEq + Debug,
+      I: IntoIterator<Item=T>,
+      J: IntoIterator<Item=T>,
+      IsSuffixOf<T>: Matcher<J> {
+    Box::new(IsSuffixOf {
+        full_sequence: full_sequence.into_iter().collect()
+    })
+}
+
+impl<T, I> Matcher<I> for IsSuffixOf<T>
+where T: PartialEq + Debug,
+      for<'all> &'all I: IntoIterator<Item=&'all T> + Debug {
+    fn check(&self, actual: &I) -> MatchResult {
+        let builder = MatchResultBuilder::for_("is_suffix_of");
+        let actual_list: Vec<_> = actual.into_iter().collect();
+
+        if actual_list.len() > self.full_sequence.len() {
+            return builder.failed_because(
+                &format!("The actual list of length {} is longer than the expected sequence of length {}",
+                         actual_list.len(), self.full_sequence.len())
+            );
+        }
+
+        let offset = self.full_sequence.len() - actual_list.len();
+        let mismatch = actual_list.iter()
+                                  .zip(self.full_sequence[offset..].iter())
+                                  .position(|(&act, exp)| act != exp);
+        match mismatch {
+            Some(idx) => builder.failed_because(
+                &format!("The actual list of length {} differs from the expected sequence at index {}: {:?} != {:?}",
+                         actual_list.len(), offset + idx, actual_list[idx], self.full_sequence[offset + idx])
+            ),
+            None => builder.matched()
+        }
+    }
+}
+
 /// Matches if the asserted collection contains *all* (possibly more) of the expected elements.
 pub struct ContainsSubset<T> {
     expected_elements: Vec<T>
@@ -230,6 +473,82 @@ where T: PartialEq + Debug  {
     }
 }
 
+/// Matches if the asserted collection equals the `expected` elements after both have been sorted.
+///
+/// This asserts equality regardless of order, duplicates have to occur equally often on both sides.
+/// The asserted collection is not modified, only references to its elements are sorted.
+/// On failure the symmetric difference is reported, i.e., the missing and the unexpected elements.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// assert_that!(&vec![3,1,2,1], equals_when_sorted(vec![1,1,2,3]));
+/// # }
+pub fn equals_when_sorted<'a, T: 'a, I: 'a>(expected: Vec<T>) -> Box<Matcher<I> + 'a>
+where T: Ord + Debug,
+      for<'all> &'all I: IntoIterator<Item=&'all T> {
+    let mut expected = expected;
+    expected.sort();
+    Box::new(move |elements: &I| {
+        let builder = MatchResultBuilder::for_("equals_when_sorted");
+        let mut actual: Vec<&T> = elements.into_iter().collect();
+        actual.sort();
+
+        let mut missing = Vec::new();
+        let mut unexpected = Vec::new();
+        let mut actual_iter = actual.into_iter().peekable();
+        let mut expected_iter = expected.iter().peekable();
+        loop {
+            let ordering = match (actual_iter.peek(), expected_iter.peek()) {
+                (Some(act), Some(exp)) => (*act).cmp(*exp),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => break
+            };
+            match ordering {
+                Ordering::Less => unexpected.push(actual_iter.next().unwrap()),
+                Ordering::Greater => missing.push(expected_iter.next().unwrap()),
+                Ordering::Equal => { actual_iter.next(); expected_iter.next(); }
+            }
+        }
+
+        if missing.is_empty() && unexpected.is_empty() {
+            builder.matched()
+        } else {
+            builder.failed_because(
+                &format!("the sorted collections differ, missing elements: {:?}, unexpected elements: {:?}", missing, unexpected)
+            )
+        }
+    })
+}
+
+/// Matches if the asserted collection shares no element with the `other` elements.
+///
+/// On failure all elements of the asserted collection which are also contained in `other` are reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// assert_that!(&vec![1,3,5], is_disjoint_from(vec![2,4,6]));
+/// # }
+pub fn is_disjoint_from<'a, T: 'a, I: 'a>(other: Vec<T>) -> Box<Matcher<I> + 'a>
+where T: PartialEq + Debug,
+      for<'all> &'all I: IntoIterator<Item=&'all T> {
+    Box::new(move |elements: &I| {
+        let builder = MatchResultBuilder::for_("is_disjoint_from");
+        let shared: Vec<_> = elements.into_iter().filter(|e| other.contains(e)).collect();
+        if shared.is_empty() {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("the following elements are shared: {:?}", shared))
+        }
+    })
+}
+
 
 fn sorted_according_to<'a,T:'a, I, P:'a>(
     predicate: P,
@@ -237,7 +556,7 @@ fn sorted_according_to<'a,T:'a, I, P:'a>(
     is_strict: bool,
 ) -> Box<Matcher<I> + 'a>
 where
-    T: Ord + Debug,
+    T: Debug,
     for<'all> &'all I: IntoIterator<Item=&'all T>,
     for<'all> P: Fn(&'all T, &'all T) -> Ordering
 {
@@ -434,136 +753,1144 @@ where T: Ord + Debug,
     sorted_strictly_by(|a: &T, b: &T| a.cmp(b), Ordering::Greater)
 }
 
-
-/// Matches if all elements in the asserted collection satisfy the given `predicate`.
+/// Matches if all consecutive pairs of elements in the asserted collection satisfy the given `relation`.
 ///
-/// An empty collection always satisfies this matcher as all (=no) element satisfies the predicate.
+/// The `relation` is applied as `relation(previous, next)` to each adjacent pair.
+/// This generalizes the sorted matchers to arbitrary relations, e.g., bounded steps between elements.
+/// Collections with less than two elements always satisfy this matcher.
+/// On failure the first violating pair and the index of its first element are reported.
 ///
 /// #Examples
 /// ```rust
 /// # #[macro_use] extern crate galvanic_assert;
 /// use galvanic_assert::matchers::collection::*;
 /// # fn main() {
-/// assert_that!(&vec![1,2,3,4,5], all_elements_satisfy(|&a| 0 <= a && a < 100));
+/// assert_that!(&vec![1,2,6,12,24], pairwise_satisfy(|a: &i32, b: &i32| b % a == 0));
+/// assert_that!(&vec![10,15,7,12], pairwise_satisfy(|a: &i32, b: &i32| (a - b).abs() <= 10));
 /// # }
-pub fn all_elements_satisfy<'a, T: 'a, I, P: 'a>(predicate: P) -> Box<Matcher<I> + 'a>
+pub fn pairwise_satisfy<'a, T: 'a, I: 'a, P: 'a>(relation: P) -> Box<Matcher<I> + 'a>
 where T: Debug,
       for<'all> &'all I: IntoIterator<Item=&'all T>,
-      for<'all> P: Fn(&'all T) -> bool {
+      for<'all> P: Fn(&'all T, &'all T) -> bool {
     Box::new(move |elements: &I| {
-        let builder = MatchResultBuilder::for_("all_elements_satisfy");
-        let nonsatisfying_elements: Vec<_> = elements.into_iter().filter(|e| !predicate(e)).collect();
-        if !nonsatisfying_elements.is_empty() {
-            builder.failed_because(
-                &format!("the following elements do not satisfy the predicate: {:?}", nonsatisfying_elements)
-            )
-        } else {
-            builder.matched()
+        let builder = MatchResultBuilder::for_("pairwise_satisfy");
+        let mut iter = elements.into_iter();
+        let mut previous = match iter.next() {
+            Some(first) => first,
+            None => return builder.matched()
+        };
+        for (idx, next) in iter.enumerate() {
+            if !relation(previous, next) {
+                return builder.failed_because(
+                    &format!("the pair ({:?}, {:?}) at index {} does not satisfy the relation", previous, next, idx)
+                );
+            }
+            previous = next;
         }
+        builder.matched()
     })
 }
 
-/// Matches if at least one element in the asserted collection satisfy the given `predicate`.
+/// Matches if the keys extracted from the elements of the asserted collection are weakly ascending.
 ///
-/// An empty collection never satisfies this matcher as no element satisfies the predicate.
+/// The `key` function is applied to each element and consecutive keys are compared.
+/// Equal consecutive keys are allowed, e.g., for repeated timestamps in an event log.
+/// An empty collection is assumed to be always sorted.
 ///
 /// #Examples
 /// ```rust
 /// # #[macro_use] extern crate galvanic_assert;
 /// use galvanic_assert::matchers::collection::*;
 /// # fn main() {
-/// assert_that!(&vec![1,2,3,4,5], some_elements_satisfy(|&a| 2 <= a && a < 5));
+/// let events = vec![(1, "start"), (3, "tick"), (3, "tock"), (5, "stop")];
+/// assert_that!(&events, non_decreasing_by_key(|e: &(i32, &str)| e.0));
 /// # }
-pub fn some_elements_satisfy<'a, T: 'a, I, P: 'a>(predicate: P) -> Box<Matcher<I> + 'a>
-where
-    T: Debug,
-    for<'all> &'all I: IntoIterator<Item=&'all T>,
-    for<'all> P: Fn(&'all T) -> bool
-{
-    Box::new(move |elements: &I| {
-        let builder = MatchResultBuilder::for_("some_elements_satisfy");
-        if !elements.into_iter().any(|ref e| predicate(e)) {
-            builder.failed_because("no elements satisfy the predicate")
-        } else {
-            builder.matched()
-        }
-    })
-}
-
-/// Matches if the map-like collection contains the given key/value pair.
-///
-/// The `Matcher` tests for this by converting the map-like data structure
-/// into a key/value pair iterator.
-///
-/// The alternative would be to use the Index trait though experiments showed
-/// that this would not be composable with `all_of!` or `any_of!`.
-pub struct HasEntry<K,V> {
-    key: K,
-    value: V
+pub fn non_decreasing_by_key<'a, T: 'a, I: 'a, K, F: 'a>(key: F) -> Box<Matcher<I> + 'a>
+where T: Debug,
+      K: Ord,
+      for<'all> &'all I: IntoIterator<Item=&'all T>,
+      F: Fn(&T) -> K {
+    rename_matcher("non_decreasing_by_key".to_owned(),
+                   sorted_according_to(move |a: &T, b: &T| key(a).cmp(&key(b)), Some(Ordering::Less), false))
 }
 
-/// Matches if the map-like collection contains the given key/value pair.
-///
-/// The `Matcher` tests for this by converting the map-like data structure
-/// into a key/value pair iterator.
+/// Matches if the keys extracted from the elements of the asserted collection are weakly descending.
 ///
-/// The alternative would be to use the Index trait though experiments showed
-/// that this would not be composable with `all_of!` or `any_of!`.
+/// The `key` function is applied to each element and consecutive keys are compared.
+/// Equal consecutive keys are allowed.
+/// An empty collection is assumed to be always sorted.
 ///
 /// #Examples
 /// ```rust
 /// # #[macro_use] extern crate galvanic_assert;
 /// use galvanic_assert::matchers::collection::*;
 /// # fn main() {
-/// let mut map = std::collections::HashMap::<i32,i32>::new();
-/// map.insert(0, 2);
-/// map.insert(1, 2);
-/// map.insert(2, 5);
-/// map.insert(3, 3);
-/// map.insert(4, 3);
-///
-/// assert_that!(&map, has_entry(1, 2));
+/// let events = vec![(5, "stop"), (3, "tock"), (3, "tick"), (1, "start")];
+/// assert_that!(&events, non_increasing_by_key(|e: &(i32, &str)| e.0));
 /// # }
-pub fn has_entry<'a,K:'a,V:'a,M:'a>(key: K, value: V) -> Box<Matcher<M> + 'a>
-where K: PartialEq + Debug,
-      V: PartialEq + Debug,
-      for<'all> &'all M: IntoIterator<Item=(&'all K, &'all V)> {
-    Box::new(HasEntry { key, value })
+pub fn non_increasing_by_key<'a, T: 'a, I: 'a, K, F: 'a>(key: F) -> Box<Matcher<I> + 'a>
+where T: Debug,
+      K: Ord,
+      for<'all> &'all I: IntoIterator<Item=&'all T>,
+      F: Fn(&T) -> K {
+    rename_matcher("non_increasing_by_key".to_owned(),
+                   sorted_according_to(move |a: &T, b: &T| key(a).cmp(&key(b)), Some(Ordering::Greater), false))
 }
 
-impl<K,V,M> Matcher<M> for HasEntry<K,V>
-where K: PartialEq + Debug,
-      V: PartialEq + Debug,
-      for<'all> &'all M: IntoIterator<Item=(&'all K, &'all V)> {
+/// Numeric types which can be added without panicking on overflow, used by `is_consecutive_from`, `prefix_sums_nonnegative` and `sums_to`.
+pub trait CheckedAddition: Sized {
+    /// Returns the sum of both values or `None` if the addition overflows.
+    fn checked_addition(&self, other: &Self) -> Option<Self>;
+}
 
-    fn check(&self, map: &M) -> MatchResult {
-        let builder = MatchResultBuilder::for_("has_entry");
-        let mut same_keys = Vec::new();
-        let mut same_values = Vec::new();
-        for (key, value) in map.into_iter() {
-            if key == &self.key && value == &self.value {
-                return builder.matched()
-            }
-            if key == &self.key {
-                same_keys.push(value);
-            }
-            if value == &self.value {
-                same_values.push(key);
-            }
+macro_rules! checked_addition_for_integers {
+    ( $($int: ty),* ) => { $(
+        impl CheckedAddition for $int {
+            fn checked_addition(&self, other: &$int) -> Option<$int> { self.checked_add(*other) }
         }
+    )* };
+}
 
-        builder.failed_because(&format!(
-            "Entry ({:?}, {:?}) not found.\n\tEntries with same key: {:?}\n\tEntries with same value: {:?}",
-            &self.key, &self.value,
-            same_keys, same_values
-        ))
-    }
+checked_addition_for_integers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Floating point values never overflow, an overflowing sum becomes infinite.
+impl CheckedAddition for f32 {
+    fn checked_addition(&self, other: &f32) -> Option<f32> { Some(self + other) }
 }
 
-/// Matches if the map-like collection contains the given key.
+/// Floating point values never overflow, an overflowing sum becomes infinite.
+impl CheckedAddition for f64 {
+    fn checked_addition(&self, other: &f64) -> Option<f64> { Some(self + other) }
+}
+
+/// Matches if the asserted collection consists of consecutive integers beginning with `start`.
 ///
-/// The `Matcher` tests for this by converting the map-like data structure
-/// into a key/value pair iterator.
+/// The elements must be `start, start+1, start+2, ...` without gaps or repetitions.
+/// An empty collection always satisfies this matcher.
+/// A sequence may end at the maximum value of the type, further elements fail the matcher instead of overflowing.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// assert_that!(&vec![5,6,7,8], is_consecutive_from(5));
+/// # }
+pub fn is_consecutive_from<'a, T: 'a, I: 'a>(start: T) -> Box<Matcher<I> + 'a>
+where T: Copy + PartialEq + From<u8> + CheckedAddition + Debug,
+      for<'all> &'all I: IntoIterator<Item=&'all T> {
+    Box::new(move |elements: &I| {
+        let builder = MatchResultBuilder::for_("is_consecutive_from");
+        let mut previous: Option<T> = None;
+        for (idx, element) in elements.into_iter().enumerate() {
+            // only compute the successor if there is an element, so a sequence may end at the maximum value
+            let expected = match previous {
+                None => start,
+                Some(p) => match p.checked_addition(&T::from(1)) {
+                    Some(successor) => successor,
+                    None => return builder.failed_because(
+                        &format!("the sequence continues at index {} with {:?} but would overflow after {:?}", idx, element, p)
+                    )
+                }
+            };
+            if *element != expected {
+                return builder.failed_because(
+                    &format!("the sequence breaks at index {}: expected {:?} but got {:?}", idx, expected, element)
+                );
+            }
+            previous = Some(expected);
+        }
+        builder.matched()
+    })
+}
+
+/// Matches if the elements of the asserted collection mapped by `f` are equal to the `expected` sequence.
+///
+/// The mapped elements are compared in order. On failure the first differing index is reported
+/// together with the mapped and the expected value, or the lengths if the sequences differ in length.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// let rows = vec![(1, "foo"), (2, "bar"), (3, "baz")];
+/// assert_that!(&rows, mapped_equals(|r: &(i32, &str)| r.0, vec![1, 2, 3]));
+/// # }
+pub fn mapped_equals<'a, T: 'a, U: 'a, I: 'a, F: 'a>(f: F, expected: Vec<U>) -> Box<Matcher<I> + 'a>
+where U: PartialEq + Debug,
+      for<'all> &'all I: IntoIterator<Item=&'all T>,
+      F: Fn(&T) -> U {
+    Box::new(move |elements: &I| {
+        let builder = MatchResultBuilder::for_("mapped_equals");
+        let mut count = 0;
+        for (idx, element) in elements.into_iter().enumerate() {
+            count += 1;
+            let mapped = f(element);
+            match expected.get(idx) {
+                Some(exp) if &mapped == exp => {},
+                Some(exp) => return builder.failed_because(
+                    &format!("the mapped elements differ at index {}: expected {:?} but got {:?}", idx, exp, mapped)
+                ),
+                None => return builder.failed_because(
+                    &format!("the mapped sequence is longer than the expected sequence of {} elements, next element is {:?}",
+                             expected.len(), mapped)
+                )
+            }
+        }
+
+        if count < expected.len() {
+            builder.failed_because(
+                &format!("the mapped sequence has {} elements but {} are expected, missing {:?}",
+                         count, expected.len(), &expected[count..])
+            )
+        } else { builder.matched() }
+    })
+}
+
+/// Splits the elements into runs of consecutive elements with equal keys.
+fn group_runs<'b, T: 'b, K, F, J>(elements: J, key: &F) -> Vec<(K, Vec<&'b T>)>
+where K: PartialEq,
+      F: Fn(&T) -> K,
+      J: IntoIterator<Item=&'b T> {
+    let mut groups: Vec<(K, Vec<&'b T>)> = Vec::new();
+    for element in elements {
+        let element_key = key(element);
+        if groups.last().map_or(false, |&(ref group_key, _)| group_key == &element_key) {
+            groups.last_mut().unwrap().1.push(element);
+        } else {
+            groups.push((element_key, vec![element]));
+        }
+    }
+    groups
+}
+
+/// Returns whether both slices contain the same elements regardless of their order.
+fn same_members<T: PartialEq>(actual: &[&T], expected: &[&T]) -> bool {
+    if actual.len() != expected.len() {
+        return false;
+    }
+    let mut remaining: Vec<&T> = expected.to_vec();
+    actual.iter().all(|a| match remaining.iter().position(|e| e == a) {
+        Some(pos) => { remaining.swap_remove(pos); true },
+        None => false
+    })
+}
+
+/// Matches if the asserted collection equals the `expected` elements while ignoring the order within groups.
+///
+/// Consecutive elements with equal keys extracted by `key` form a group.
+/// The groups need to appear in the same order and with the same keys in both collections,
+/// but the elements within a group may be permuted, e.g., to compare the output of an unstable sort.
+/// On failure the first differing group is reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// let sorted_by_age = vec![(20, "bob"), (20, "alice"), (30, "carol")];
+/// assert_that!(&sorted_by_age, equals_ignoring_order_within_groups(
+///     vec![(20, "alice"), (20, "bob"), (30, "carol")],
+///     |p: &(i32, &str)| p.0
+/// ));
+/// # }
+pub fn equals_ignoring_order_within_groups<'a, T: 'a, I: 'a, K, F: 'a>(expected: Vec<T>, key: F) -> Box<Matcher<I> + 'a>
+where T: PartialEq + Debug,
+      K: PartialEq + Debug,
+      for<'all> &'all I: IntoIterator<Item=&'all T>,
+      F: Fn(&T) -> K {
+    Box::new(move |elements: &I| {
+        let builder = MatchResultBuilder::for_("equals_ignoring_order_within_groups");
+        let actual_groups = group_runs(elements, &key);
+        let expected_groups = group_runs(&expected, &key);
+
+        for (idx, (actual_group, expected_group)) in actual_groups.iter().zip(expected_groups.iter()).enumerate() {
+            if actual_group.0 != expected_group.0 || !same_members(&actual_group.1, &expected_group.1) {
+                return builder.failed_because(
+                    &format!("group {} differs: expected key {:?} with members {:?} but got key {:?} with members {:?}",
+                             idx, expected_group.0, expected_group.1, actual_group.0, actual_group.1)
+                );
+            }
+        }
+        if actual_groups.len() != expected_groups.len() {
+            return builder.failed_because(
+                &format!("expected {} groups but got {} groups", expected_groups.len(), actual_groups.len())
+            );
+        }
+        builder.matched()
+    })
+}
+
+/// Matches if the elements of the asserted collection are grouped by the keys extracted with `key`.
+///
+/// Elements with equal keys have to form a single contiguous run, i.e., once the key changes the previous key never reappears.
+/// The groups themselves may appear in any order.
+/// On failure the recurring key is reported together with the index range of its earlier run and the index of its reappearance.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// let words = vec!["cat", "cow", "apple", "ant", "bee"];
+/// assert_that!(&words, is_grouped_by_key(|w: &&str| w.chars().next()));
+/// # }
+pub fn is_grouped_by_key<'a, T: 'a, I: 'a, K, F: 'a>(key: F) -> Box<Matcher<I> + 'a>
+where K: PartialEq + Debug,
+      for<'all> &'all I: IntoIterator<Item=&'all T>,
+      F: Fn(&T) -> K {
+    Box::new(move |elements: &I| {
+        let builder = MatchResultBuilder::for_("is_grouped_by_key");
+        // completed runs as (key, first index, last index)
+        let mut closed_runs: Vec<(K, usize, usize)> = Vec::new();
+        let mut current: Option<(K, usize)> = None;
+        for (idx, element) in elements.into_iter().enumerate() {
+            let element_key = key(element);
+            if current.as_ref().map_or(false, |&(ref current_key, _)| current_key == &element_key) {
+                continue;
+            }
+            if let Some(&(ref closed_key, first, last)) = closed_runs.iter().find(|run| run.0 == element_key) {
+                return builder.failed_because(
+                    &format!("key {:?} of the run at indices {}..={} reappears at index {}", closed_key, first, last, idx)
+                );
+            }
+            if let Some((previous_key, first)) = current.take() {
+                closed_runs.push((previous_key, first, idx - 1));
+            }
+            current = Some((element_key, idx));
+        }
+        builder.matched()
+    })
+}
+
+/// Matches if the keys extracted from the elements of the asserted collection are pairwise distinct.
+///
+/// The `key` function is applied to each element, so elements which differ as a whole may still collide on their key.
+/// On failure the first two elements sharing a key are reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// let rows = vec![(1, "foo"), (2, "foo"), (3, "bar")];
+/// assert_that!(&rows, distinct_by_key(|r: &(i32, &str)| r.0));
+/// # }
+pub fn distinct_by_key<'a, T: 'a, I: 'a, K, F: 'a>(key: F) -> Box<Matcher<I> + 'a>
+where T: Debug,
+      K: Eq + Hash,
+      for<'all> &'all I: IntoIterator<Item=&'all T>,
+      F: Fn(&T) -> K {
+    Box::new(move |elements: &I| {
+        let builder = MatchResultBuilder::for_("distinct_by_key");
+        let mut seen: HashMap<K, (usize, &T)> = HashMap::new();
+        for (idx, element) in elements.into_iter().enumerate() {
+            match seen.entry(key(element)) {
+                Entry::Occupied(entry) => {
+                    let &(first_idx, first) = entry.get();
+                    return builder.failed_because(
+                        &format!("elements {:?} at index {} and {:?} at index {} have the same key", first, first_idx, element, idx)
+                    );
+                },
+                Entry::Vacant(entry) => { entry.insert((idx, element)); }
+            }
+        }
+        builder.matched()
+    })
+}
+
+/// Matches if the asserted collection contains exactly `expected` distinct elements.
+///
+/// On failure the actual number of distinct elements is reported together with a few of the duplicated elements.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// assert_that!(&vec![1,2,2,3,3,3], distinct_count_is(3));
+/// # }
+pub fn distinct_count_is<'a, T: 'a, I: 'a>(expected: usize) -> Box<Matcher<I> + 'a>
+where T: Eq + Hash + Debug,
+      for<'all> &'all I: IntoIterator<Item=&'all T> {
+    Box::new(move |elements: &I| {
+        let builder = MatchResultBuilder::for_("distinct_count_is");
+        let mut counts: HashMap<&T, usize> = HashMap::new();
+        let mut duplicates = Vec::new();
+        for element in elements {
+            let count = counts.entry(element).or_insert(0);
+            *count += 1;
+            if *count == 2 && duplicates.len() < 5 {
+                duplicates.push(element);
+            }
+        }
+
+        if counts.len() == expected {
+            builder.matched()
+        } else {
+            builder.failed_because(
+                &format!("expected {} distinct elements but found {}; duplicated elements include {:?}",
+                         expected, counts.len(), duplicates)
+            )
+        }
+    })
+}
+
+/// Matches if the asserted collection is non-empty and all of its elements are equal to `expected`.
+///
+/// In contrast to `all_elements_satisfy` an empty collection does not satisfy this matcher.
+/// On failure the indices of all differing elements are reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// assert_that!(&vec![0u8; 16], all_equal_to(0));
+/// # }
+pub fn all_equal_to<'a, T: 'a, I: 'a>(expected: T) -> Box<Matcher<I> + 'a>
+where T: PartialEq + Debug,
+      for<'all> &'all I: IntoIterator<Item=&'all T> {
+    Box::new(move |elements: &I| {
+        let builder = MatchResultBuilder::for_("all_equal_to");
+        let mut count = 0;
+        let mut offending = Vec::new();
+        for (idx, element) in elements.into_iter().enumerate() {
+            count += 1;
+            if element != &expected {
+                offending.push(idx);
+            }
+        }
+
+        if count == 0 {
+            builder.failed_because(&format!("the collection is empty, expected elements equal to {:?}", expected))
+        } else if !offending.is_empty() {
+            builder.failed_because(
+                &format!("the elements at indices {:?} are not equal to {:?}", offending, expected)
+            )
+        } else { builder.matched() }
+    })
+}
+
+/// Matches if the asserted collection reads the same forwards and backwards.
+///
+/// An empty collection is a palindrome.
+/// On failure the first pair of indices with differing elements is reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// assert_that!(&vec![1,2,3,2,1], is_palindrome());
+/// # }
+pub fn is_palindrome<'a, T: 'a, I: 'a>() -> Box<Matcher<I> + 'a>
+where T: PartialEq + Debug,
+      for<'all> &'all I: IntoIterator<Item=&'all T> {
+    Box::new(|elements: &I| {
+        let builder = MatchResultBuilder::for_("is_palindrome");
+        let elements: Vec<_> = elements.into_iter().collect();
+        let len = elements.len();
+        for idx in 0..len / 2 {
+            let mirrored = len - 1 - idx;
+            if elements[idx] != elements[mirrored] {
+                return builder.failed_because(
+                    &format!("element {:?} at index {} differs from element {:?} at index {}",
+                             elements[idx], idx, elements[mirrored], mirrored)
+                );
+            }
+        }
+        builder.matched()
+    })
+}
+
+/// Matches if the asserted collection is a cyclic rotation of the `expected` sequence.
+///
+/// Both sequences must have the same length and the asserted collection must equal `expected`
+/// after moving some number of leading elements to its end. An empty collection is a rotation of an empty sequence.
+/// On failure both sequences are reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// assert_that!(&vec![3,4,1,2], is_rotation_of(vec![1,2,3,4]));
+/// # }
+pub fn is_rotation_of<'a, T: 'a, I: 'a>(expected: Vec<T>) -> Box<Matcher<I> + 'a>
+where T: PartialEq + Debug,
+      for<'all> &'all I: IntoIterator<Item=&'all T> {
+    Box::new(move |elements: &I| {
+        let builder = MatchResultBuilder::for_("is_rotation_of");
+        let elements: Vec<_> = elements.into_iter().collect();
+        let len = elements.len();
+        if len != expected.len() {
+            return builder.failed_because(
+                &format!("the collection {:?} of length {} cannot be a rotation of {:?} of length {}",
+                         elements, len, expected, expected.len())
+            );
+        }
+
+        let is_rotation = len == 0 || (0..len).any(|shift| {
+            expected.iter().enumerate().all(|(idx, exp)| elements[(shift + idx) % len] == exp)
+        });
+        if is_rotation {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("no rotation of {:?} equals {:?}", elements, expected))
+        }
+    })
+}
+
+/// Matches if all elements in the asserted collection of `Result`s are `Ok`.
+///
+/// An empty collection always satisfies this matcher.
+/// On failure the index and error of each `Err` element are reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// let batch: Vec<Result<i32, String>> = vec![Ok(1), Ok(2)];
+/// assert_that!(&batch, all_ok());
+/// # }
+pub fn all_ok<'a, T: 'a, E: 'a, I: 'a>() -> Box<Matcher<I> + 'a>
+where E: Debug,
+      for<'all> &'all I: IntoIterator<Item=&'all Result<T, E>> {
+    Box::new(|elements: &I| {
+        let builder = MatchResultBuilder::for_("all_ok");
+        let failed_msgs: Vec<_> = elements.into_iter().enumerate().filter_map(|(idx, element)| match *element {
+            Err(ref err) => Some(format!("element at index {} is Err({:?})", idx, err)),
+            Ok(_) => None
+        }).collect();
+
+        if failed_msgs.is_empty() { builder.matched() }
+        else { builder.failed_because(&failed_msgs.join("\n")) }
+    })
+}
+
+/// Matches if all elements in the asserted collection satisfy the given `predicate`.
+///
+/// An empty collection always satisfies this matcher as all (=no) element satisfies the predicate.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// assert_that!(&vec![1,2,3,4,5], all_elements_satisfy(|&a| 0 <= a && a < 100));
+/// # }
+pub fn all_elements_satisfy<'a, T: 'a, I, P: 'a>(predicate: P) -> Box<Matcher<I> + 'a>
+where T: Debug,
+      for<'all> &'all I: IntoIterator<Item=&'all T>,
+      for<'all> P: Fn(&'all T) -> bool {
+    Box::new(move |elements: &I| {
+        let builder = MatchResultBuilder::for_("all_elements_satisfy");
+        let nonsatisfying_elements: Vec<_> = elements.into_iter().filter(|e| !predicate(e)).collect();
+        if !nonsatisfying_elements.is_empty() {
+            builder.failed_because(
+                &format!("the following elements do not satisfy the predicate: {:?}", nonsatisfying_elements)
+            )
+        } else {
+            builder.matched()
+        }
+    })
+}
+
+/// Matches if the asserted collection is non-empty and all of its elements satisfy the given `matcher`.
+///
+/// In contrast to `all_elements_satisfy` an empty collection does not satisfy this matcher,
+/// e.g., to catch a faulty filter which removed all elements.
+/// On failure either the empty collection or the index, element, and reason of the first failing element are reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// assert_that!(&vec![2,4,6], non_empty_and_each(lt(10)));
+/// assert_that!(
+///     assert_that!(&Vec::<i32>::new(), non_empty_and_each(lt(10))),
+///     panics
+/// );
+/// # }
+pub fn non_empty_and_each<'a, T: 'a, I: 'a>(matcher: Box<Matcher<T> + 'a>) -> Box<Matcher<I> + 'a>
+where T: Debug,
+      for<'all> &'all I: IntoIterator<Item=&'all T> {
+    Box::new(move |elements: &I| {
+        let builder = MatchResultBuilder::for_("non_empty_and_each");
+        let mut is_empty = true;
+        for (idx, element) in elements.into_iter().enumerate() {
+            is_empty = false;
+            if let MatchResult::Failed { name, reason } = matcher.check(element) {
+                return builder.failed_because(
+                    &format!("element {:?} at index {} does not satisfy matcher '{}':\n{}", element, idx, name, reason)
+                );
+            }
+        }
+
+        if is_empty {
+            builder.failed_because("the collection is empty, expected at least one element")
+        } else { builder.matched() }
+    })
+}
+
+/// Matches if at least one element in the asserted collection satisfy the given `predicate`.
+///
+/// An empty collection never satisfies this matcher as no element satisfies the predicate.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// assert_that!(&vec![1,2,3,4,5], some_elements_satisfy(|&a| 2 <= a && a < 5));
+/// # }
+pub fn some_elements_satisfy<'a, T: 'a, I, P: 'a>(predicate: P) -> Box<Matcher<I> + 'a>
+where
+    T: Debug,
+    for<'all> &'all I: IntoIterator<Item=&'all T>,
+    for<'all> P: Fn(&'all T) -> bool
+{
+    Box::new(move |elements: &I| {
+        let builder = MatchResultBuilder::for_("some_elements_satisfy");
+        if !elements.into_iter().any(|ref e| predicate(e)) {
+            builder.failed_because("no elements satisfy the predicate")
+        } else {
+            builder.matched()
+        }
+    })
+}
+
+/// Matches if the `Display` representation of at least one element in the asserted collection contains `substring`.
+///
+/// This allows to assert on collections of types without a `PartialEq` implementation, e.g., errors.
+/// On failure the rendered forms of up to three elements sharing the longest prefix with `substring` are reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// use std::io::{Error, ErrorKind};
+/// # fn main() {
+/// let errors = vec![Error::new(ErrorKind::Other, "disk full"), Error::new(ErrorKind::TimedOut, "read timeout")];
+/// assert_that!(&errors, contains_element_displaying("timeout"));
+/// # }
+pub fn contains_element_displaying<'a, T: 'a, I: 'a>(substring: &str) -> Box<Matcher<I> + 'a>
+where T: Display,
+      for<'all> &'all I: IntoIterator<Item=&'all T> {
+    let substring = substring.to_owned();
+    Box::new(move |elements: &I| {
+        let builder = MatchResultBuilder::for_("contains_element_displaying");
+        let mut near_misses = Vec::new();
+        for element in elements {
+            let rendered = format!("{}", element);
+            if rendered.contains(&substring) {
+                return builder.matched();
+            }
+            let shared_prefix = substring.char_indices()
+                                         .map(|(idx, c)| idx + c.len_utf8())
+                                         .take_while(|&end| rendered.contains(&substring[..end]))
+                                         .last()
+                                         .unwrap_or(0);
+            near_misses.push((shared_prefix, rendered));
+        }
+
+        if near_misses.is_empty() {
+            return builder.failed_because(&format!("the collection is empty, expected an element containing {:?}", substring));
+        }
+        near_misses.sort_by_key(|near_miss| Reverse(near_miss.0));
+        let closest: Vec<_> = near_misses.into_iter().take(3).map(|(_, rendered)| rendered).collect();
+        builder.failed_because(
+            &format!("no element contains {:?}, closest elements are:\n\t{}", substring, closest.join("\n\t"))
+        )
+    })
+}
+
+/// Matches if the asserted collection contains at least `min_length` consecutive elements satisfying the given `predicate`.
+///
+/// On failure the longest run of consecutive satisfying elements is reported.
+/// A `min_length` of zero is satisfied by every collection.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// assert_that!(&vec![1,7,8,9,2,8], has_run_satisfying(3, |&a| a > 5));
+/// # }
+pub fn has_run_satisfying<'a, T: 'a, I, P: 'a>(min_length: usize, predicate: P) -> Box<Matcher<I> + 'a>
+where T: Debug,
+      for<'all> &'all I: IntoIterator<Item=&'all T>,
+      for<'all> P: Fn(&'all T) -> bool {
+    Box::new(move |elements: &I| {
+        let builder = MatchResultBuilder::for_("has_run_satisfying");
+        let mut longest_run = Vec::new();
+        let mut current_run = Vec::new();
+        for element in elements.into_iter() {
+            if predicate(element) {
+                current_run.push(element);
+                if current_run.len() > longest_run.len() {
+                    longest_run = current_run.clone();
+                }
+            } else {
+                current_run.clear();
+            }
+        }
+
+        if longest_run.len() >= min_length {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!(
+                "expected a run of at least {} elements satisfying the predicate, the longest run has {} elements: {:?}",
+                min_length, longest_run.len(), longest_run
+            ))
+        }
+    })
+}
+
+/// Matches if the elements of the asserted collection alternate between satisfying `predicate_a` and `predicate_b`.
+///
+/// Elements at even indices need to satisfy `predicate_a`, elements at odd indices need to satisfy `predicate_b`.
+/// An empty collection always satisfies this matcher.
+/// On failure the first element breaking the alternation is reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// let frames = vec!["req:1", "resp:1", "req:2", "resp:2"];
+/// assert_that!(&frames, alternates(|f: &&str| f.starts_with("req"), |f: &&str| f.starts_with("resp")));
+/// # }
+pub fn alternates<'a, T: 'a, I: 'a, P: 'a, Q: 'a>(predicate_a: P, predicate_b: Q) -> Box<Matcher<I> + 'a>
+where T: Debug,
+      for<'all> &'all I: IntoIterator<Item=&'all T>,
+      for<'all> P: Fn(&'all T) -> bool,
+      for<'all> Q: Fn(&'all T) -> bool {
+    Box::new(move |elements: &I| {
+        let builder = MatchResultBuilder::for_("alternates");
+        for (idx, element) in elements.into_iter().enumerate() {
+            let (satisfied, predicate_name) = if idx % 2 == 0 { (predicate_a(element), "first") }
+                                              else { (predicate_b(element), "second") };
+            if !satisfied {
+                return builder.failed_because(
+                    &format!("element {:?} at index {} does not satisfy the {} predicate", element, idx, predicate_name)
+                );
+            }
+        }
+        builder.matched()
+    })
+}
+
+/// Matches if no value repeats consecutively more than `max_length` times in the asserted collection.
+///
+/// On failure the first offending value, the index where its run starts, and the run's length are reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// assert_that!(&vec![1,1,1,2,2,1,1,1], no_run_longer_than(3));
+/// # }
+pub fn no_run_longer_than<'a, T: 'a, I: 'a>(max_length: usize) -> Box<Matcher<I> + 'a>
+where T: PartialEq + Debug,
+      for<'all> &'all I: IntoIterator<Item=&'all T> {
+    Box::new(move |elements: &I| {
+        let builder = MatchResultBuilder::for_("no_run_longer_than");
+        let mut run: Option<(&T, usize, usize)> = None;
+        for (idx, element) in elements.into_iter().enumerate() {
+            run = match run {
+                Some((value, start, length)) if value == element => Some((value, start, length + 1)),
+                _ => Some((element, idx, 1))
+            };
+            if let Some((value, start, _)) = run.filter(|&(_, _, length)| length > max_length) {
+                let length = elements.into_iter().skip(start).take_while(|e| *e == value).count();
+                return builder.failed_because(
+                    &format!("{:?} repeats {} times starting at index {} but at most {} repetitions are allowed",
+                             value, length, start, max_length)
+                );
+            }
+        }
+        builder.matched()
+    })
+}
+
+/// Matches if exactly `expected_true_count` elements in the asserted collection satisfy the given `predicate`.
+///
+/// On failure the actual count and a few examples of satisfying and non-satisfying elements are reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// assert_that!(&vec![1,2,3,4,5], partitions_as(|&a| a % 2 == 0, 2));
+/// # }
+pub fn partitions_as<'a, T: 'a, I, P: 'a>(predicate: P, expected_true_count: usize) -> Box<Matcher<I> + 'a>
+where T: Debug,
+      for<'all> &'all I: IntoIterator<Item=&'all T>,
+      for<'all> P: Fn(&'all T) -> bool {
+    const MAX_EXAMPLES: usize = 3;
+    Box::new(move |elements: &I| {
+        let builder = MatchResultBuilder::for_("partitions_as");
+        let (satisfying, nonsatisfying): (Vec<_>, Vec<_>) = elements.into_iter().partition(|e| predicate(e));
+        if satisfying.len() == expected_true_count {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!(
+                "{} elements satisfy the predicate but expected {}\n\tSatisfying elements, e.g.: {:?}\n\tNon-satisfying elements, e.g.: {:?}",
+                satisfying.len(), expected_true_count,
+                &satisfying[..satisfying.len().min(MAX_EXAMPLES)],
+                &nonsatisfying[..nonsatisfying.len().min(MAX_EXAMPLES)]
+            ))
+        }
+    })
+}
+
+/// Matches if exactly `expected_matching` elements of the asserted collection satisfy the given `Matcher`
+/// and exactly `expected_nonmatching` elements do not.
+///
+/// On failure the actual counts and a few examples of matching and non-matching elements are reported.
+/// The examples of non-matching elements include the failure reason of the `Matcher`.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// assert_that!(&vec![1,2,3,4,5], partition_counts(gt(3), 2, 3));
+/// # }
+pub fn partition_counts<'a, T: 'a, I: 'a>(matcher: Box<Matcher<T> + 'a>, expected_matching: usize, expected_nonmatching: usize)
+    -> Box<Matcher<I> + 'a>
+where T: Debug,
+      for<'all> &'all I: IntoIterator<Item=&'all T> {
+    const MAX_EXAMPLES: usize = 3;
+    Box::new(move |elements: &I| {
+        let builder = MatchResultBuilder::for_("partition_counts");
+        let mut matching = Vec::new();
+        let mut nonmatching = Vec::new();
+        for element in elements.into_iter() {
+            match matcher.check(element) {
+                MatchResult::Matched { .. } => matching.push(element),
+                MatchResult::Failed { reason, .. } => nonmatching.push((element, reason))
+            }
+        }
+
+        if matching.len() == expected_matching && nonmatching.len() == expected_nonmatching {
+            builder.matched()
+        } else {
+            let nonmatching_examples: Vec<_> = nonmatching.iter().take(MAX_EXAMPLES)
+                .map(|&(element, ref reason)| format!("{:?} because: {}", element, reason))
+                .collect();
+            builder.failed_because(&format!(
+                "{} elements match and {} do not but expected {} and {}\n\tMatching elements, e.g.: {:?}\n\tNon-matching elements, e.g.:\n\t\t{}",
+                matching.len(), nonmatching.len(), expected_matching, expected_nonmatching,
+                &matching[..matching.len().min(MAX_EXAMPLES)],
+                nonmatching_examples.join("\n\t\t")
+            ))
+        }
+    })
+}
+
+/// Matches if the sum of the elements in the asserted collection equals the expected value.
+///
+/// The sum of an empty collection is the neutral element of the addition, e.g., `0`.
+/// This matcher should not be used for floating point elements as the sum is compared for equality.
+/// Use `sum_close_to` instead.
+/// If the sum overflows the matcher fails instead of panicking.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// assert_that!(&vec![10,20,30,40], sums_to(100));
+/// # }
+pub fn sums_to<'a, T: 'a, I: 'a>(expected: T) -> Box<Matcher<I> + 'a>
+where T: PartialEq + Default + CheckedAddition + Debug,
+      for<'all> &'all I: IntoIterator<Item=&'all T> + Debug {
+    Box::new(move |elements: &I| {
+        let builder = MatchResultBuilder::for_("sums_to");
+        let mut sum = T::default();
+        for (idx, element) in elements.into_iter().enumerate() {
+            sum = match sum.checked_addition(element) {
+                Some(sum) => sum,
+                None => return builder.failed_because(
+                    &format!("the sum of the elements of {:?} overflows at index {} when adding {:?} to {:?}",
+                             elements, idx, element, sum)
+                )
+            };
+        }
+        if sum == expected {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("the elements of {:?} sum to {:?} but expected {:?}", elements, sum, expected))
+        }
+    })
+}
+
+/// Matches if the sum of the floating point elements in the asserted collection is within `eps` of the expected value.
+///
+/// The sum of an empty collection is the neutral element of the addition, e.g., `0.0`.
+/// On failure the actual sum and its difference to the expected value are reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// assert_that!(&vec![0.1; 10], sum_close_to(1.0, 1e-9));
+/// # }
+pub fn sum_close_to<'a, T: 'a, I: 'a>(expected: T, eps: T) -> Box<Matcher<I> + 'a>
+where T: Copy + PartialOrd + std::ops::Sub<Output=T> + Debug + for<'all> std::iter::Sum<&'all T>,
+      for<'all> &'all I: IntoIterator<Item=&'all T> {
+    Box::new(move |elements: &I| {
+        let builder = MatchResultBuilder::for_("sum_close_to");
+        let sum: T = elements.into_iter().sum();
+        let delta = if sum > expected { sum - expected } else { expected - sum };
+        if delta <= eps {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("the elements sum to {:?} which differs from {:?} by {:?} exceeding {:?}",
+                                            sum, expected, delta, eps))
+        }
+    })
+}
+
+/// Matches if the running total of the elements in the asserted collection never drops below zero.
+///
+/// The running total starts at `T::default()`, i.e., zero for numeric types, and the elements are added in order.
+/// An empty collection always satisfies this matcher.
+/// On failure the first index where the running total becomes negative and the partial sum are reported.
+/// If the running total of integers overflows the matcher fails at that index instead of panicking.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// assert_that!(&vec![10, -5, 3, -8], prefix_sums_nonnegative());
+/// # }
+pub fn prefix_sums_nonnegative<'a, T: 'a, I: 'a>() -> Box<Matcher<I> + 'a>
+where T: Copy + Default + PartialOrd + CheckedAddition + Debug,
+      for<'all> &'all I: IntoIterator<Item=&'all T> {
+    Box::new(|elements: &I| {
+        let builder = MatchResultBuilder::for_("prefix_sums_nonnegative");
+        let zero = T::default();
+        let mut total = zero;
+        for (idx, element) in elements.into_iter().enumerate() {
+            total = match total.checked_addition(element) {
+                Some(sum) => sum,
+                None => return builder.failed_because(
+                    &format!("the running total {:?} overflows at index {} when adding {:?}", total, idx, element)
+                )
+            };
+            if total < zero {
+                return builder.failed_because(
+                    &format!("the running total drops to {:?} at index {} after adding {:?}", total, idx, element)
+                );
+            }
+        }
+        builder.matched()
+    })
+}
+
+/// Matches if the largest element in the asserted collection equals the expected value.
+///
+/// An empty collection never satisfies this matcher.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// assert_that!(&vec![12,42,7], has_max(42));
+/// # }
+pub fn has_max<'a, T: 'a, I: 'a>(expected: T) -> Box<Matcher<I> + 'a>
+where T: Ord + Debug,
+      for<'all> &'all I: IntoIterator<Item=&'all T> + Debug {
+    Box::new(move |elements: &I| {
+        let builder = MatchResultBuilder::for_("has_max");
+        match elements.into_iter().max() {
+            Some(max) if max == &expected => builder.matched(),
+            Some(max) => builder.failed_because(
+                &format!("the largest element of {:?} is {:?} but expected {:?}", elements, max, expected)
+            ),
+            None => builder.failed_because(
+                &format!("the collection is empty but expected the largest element {:?}", expected)
+            )
+        }
+    })
+}
+
+/// Matches if the smallest element in the asserted collection equals the expected value.
+///
+/// An empty collection never satisfies this matcher.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// assert_that!(&vec![12,42,7], has_min(7));
+/// # }
+pub fn has_min<'a, T: 'a, I: 'a>(expected: T) -> Box<Matcher<I> + 'a>
+where T: Ord + Debug,
+      for<'all> &'all I: IntoIterator<Item=&'all T> + Debug {
+    Box::new(move |elements: &I| {
+        let builder = MatchResultBuilder::for_("has_min");
+        match elements.into_iter().min() {
+            Some(min) if min == &expected => builder.matched(),
+            Some(min) => builder.failed_because(
+                &format!("the smallest element of {:?} is {:?} but expected {:?}", elements, min, expected)
+            ),
+            None => builder.failed_because(
+                &format!("the collection is empty but expected the smallest element {:?}", expected)
+            )
+        }
+    })
+}
+
+/// Matches if the asserted (single) value equals the largest element of the given `collection`.
+///
+/// An empty collection never satisfies this matcher.
+/// On failure the actual largest element is reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// assert_that!(&42, is_max_of(vec![12,42,7]));
+/// # }
+pub fn is_max_of<'a, T: 'a, I>(collection: I) -> Box<Matcher<T> + 'a>
+where T: Ord + Debug,
+      I: IntoIterator<Item=T> {
+    let max = collection.into_iter().max();
+    Box::new(move |actual: &T| {
+        let builder = MatchResultBuilder::for_("is_max_of");
+        match max {
+            Some(ref max) if max == actual => builder.matched(),
+            Some(ref max) => builder.failed_because(
+                &format!("{:?} is not the largest element, the largest element is {:?}", actual, max)
+            ),
+            None => builder.failed_because(
+                &format!("the collection is empty, so {:?} cannot be its largest element", actual)
+            )
+        }
+    })
+}
+
+/// Matches if the asserted (single) value equals the smallest element of the given `collection`.
+///
+/// An empty collection never satisfies this matcher.
+/// On failure the actual smallest element is reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// assert_that!(&7, is_min_of(vec![12,42,7]));
+/// # }
+pub fn is_min_of<'a, T: 'a, I>(collection: I) -> Box<Matcher<T> + 'a>
+where T: Ord + Debug,
+      I: IntoIterator<Item=T> {
+    let min = collection.into_iter().min();
+    Box::new(move |actual: &T| {
+        let builder = MatchResultBuilder::for_("is_min_of");
+        match min {
+            Some(ref min) if min == actual => builder.matched(),
+            Some(ref min) => builder.failed_because(
+                &format!("{:?} is not the smallest element, the smallest element is {:?}", actual, min)
+            ),
+            None => builder.failed_because(
+                &format!("the collection is empty, so {:?} cannot be its smallest element", actual)
+            )
+        }
+    })
+}
+
+/// Matches if the map-like collection contains the given key/value pair.
+///
+/// The `Matcher` tests for this by converting the map-like data structure
+/// into a key/value pair iterator.
+///
+/// The alternative would be to use the Index trait though experiments showed
+/// that this would not be composable with `all_of!` or `any_of!`.
+pub struct HasEntry<K,V> {
+    key: K,
+    value: V
+}
+
+/// Matches if the map-like collection contains the given key/value pair.
+///
+/// The `Matcher` tests for this by converting the map-like data structure
+/// into a key/value pair iterator.
+///
+/// The alternative would be to use the Index trait though experiments showed
+/// that this would not be composable with `all_of!` or `any_of!`.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// let mut map = std::collections::HashMap::<i32,i32>::new();
+/// map.insert(0, 2);
+/// map.insert(1, 2);
+/// map.insert(2, 5);
+/// map.insert(3, 3);
+/// map.insert(4, 3);
+///
+/// assert_that!(&map, has_entry(1, 2));
+/// # }
+pub fn has_entry<'a,K:'a,V:'a,M:'a>(key: K, value: V) -> Box<Matcher<M> + 'a>
+where K: PartialEq + Debug,
+      V: PartialEq + Debug,
+      for<'all> &'all M: IntoIterator<Item=(&'all K, &'all V)> {
+    Box::new(HasEntry { key, value })
+}
+
+impl<K,V,M> Matcher<M> for HasEntry<K,V>
+where K: PartialEq + Debug,
+      V: PartialEq + Debug,
+      for<'all> &'all M: IntoIterator<Item=(&'all K, &'all V)> {
+
+    fn check(&self, map: &M) -> MatchResult {
+        let builder = MatchResultBuilder::for_("has_entry");
+        let mut same_keys = Vec::new();
+        let mut same_values = Vec::new();
+        for (key, value) in map.into_iter() {
+            if key == &self.key && value == &self.value {
+                return builder.matched()
+            }
+            if key == &self.key {
+                same_keys.push(value);
+            }
+            if value == &self.value {
+                same_values.push(key);
+            }
+        }
+
+        builder.failed_because(&format!(
+            "Entry ({:?}, {:?}) not found.\n\tEntries with same key: {:?}\n\tEntries with same value: {:?}",
+            &self.key, &self.value,
+            same_keys, same_values
+        ))
+    }
+}
+
+/// Matches if the map-like collection contains the given key.
+///
+/// The `Matcher` tests for this by converting the map-like data structure
+/// into a key/value pair iterator.
 ///
 /// The alternative would be to use the Index trait though experiments showed
 /// that this would not be composable with `all_of!` or `any_of!`.
@@ -664,3 +1991,428 @@ where V: PartialEq + Debug,
         builder.failed_because(&format!("No entry with value {:?} found", &self.value))
     }
 }
+
+/// Matches if the map-like collection contains the given key and its value satisfies the given `Matcher`.
+///
+/// The `Matcher` tests for this by converting the map-like data structure
+/// into a key/value pair iterator instead of using the `Index` trait.
+/// This makes it composable with `all_of!` or `any_of!`.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// let mut map = std::collections::HashMap::<&str,i32>::new();
+/// map.insert("a", 2);
+/// map.insert("b", 5);
+///
+/// assert_that!(&map, all_of!(entry("a", gt(0)), entry("b", lt(10))));
+/// # }
+pub fn entry<'a, K: 'a, V: 'a, M: 'a>(key: K, value_matcher: Box<Matcher<V> + 'a>) -> Box<Matcher<M> + 'a>
+where K: PartialEq + Debug,
+      for<'all> &'all M: IntoIterator<Item=(&'all K, &'all V)> {
+    Box::new(move |map: &M| {
+        let builder = MatchResultBuilder::for_("entry");
+        match map.into_iter().find(|&(k, _)| k == &key) {
+            Some((_, value)) => match value_matcher.check(value) {
+                MatchResult::Failed { name, reason } => builder.failed_because(
+                    &format!("Matcher '{}' failed for the value of key {:?}:\n{}", name, key, reason)
+                ),
+                matched => matched
+            },
+            None => builder.failed_because(&format!("No entry with key {:?} found", key))
+        }
+    })
+}
+
+/// Matches if all values of the map-like collection satisfy the given `Matcher`.
+///
+/// The `Matcher` tests for this by converting the map-like data structure
+/// into a key/value pair iterator. All failing entries are reported together with their keys.
+/// An empty map always satisfies this matcher.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// let mut map = std::collections::HashMap::<i32,i32>::new();
+/// map.insert(0, 2);
+/// map.insert(1, 5);
+///
+/// assert_that!(&map, all_values_satisfy(lt(10)));
+/// # }
+pub fn all_values_satisfy<'a, K: 'a, V: 'a, M: 'a>(matcher: Box<Matcher<V> + 'a>) -> Box<Matcher<M> + 'a>
+where K: Debug,
+      V: Debug,
+      for<'all> &'all M: IntoIterator<Item=(&'all K, &'all V)> {
+    Box::new(move |map: &M| {
+        let builder = MatchResultBuilder::for_("all_values_satisfy");
+        let failed_msgs: Vec<_> = map.into_iter().filter_map(|(key, value)| match matcher.check(value) {
+            MatchResult::Failed { name, reason } =>
+                Some(format!("Matcher '{}' failed for value {:?} of key {:?}:\n{}", name, value, key, reason)),
+            MatchResult::Matched { .. } => None
+        }).collect();
+
+        if failed_msgs.is_empty() { builder.matched() }
+        else { builder.failed_because(&failed_msgs.join("\n")) }
+    })
+}
+
+/// Matches if all keys of the map-like collection satisfy the given `Matcher`.
+///
+/// The `Matcher` tests for this by converting the map-like data structure
+/// into a key/value pair iterator. All failing keys are reported.
+/// An empty map always satisfies this matcher.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// let mut map = std::collections::HashMap::<i32,i32>::new();
+/// map.insert(0, 2);
+/// map.insert(1, 5);
+///
+/// assert_that!(&map, all_keys_satisfy(lt(2)));
+/// # }
+pub fn all_keys_satisfy<'a, K: 'a, V: 'a, M: 'a>(matcher: Box<Matcher<K> + 'a>) -> Box<Matcher<M> + 'a>
+where K: Debug,
+      for<'all> &'all M: IntoIterator<Item=(&'all K, &'all V)> {
+    Box::new(move |map: &M| {
+        let builder = MatchResultBuilder::for_("all_keys_satisfy");
+        let failed_msgs: Vec<_> = map.into_iter().filter_map(|(key, _)| match matcher.check(key) {
+            MatchResult::Failed { name, reason } =>
+                Some(format!("Matcher '{}' failed for key {:?}:\n{}", name, key, reason)),
+            MatchResult::Matched { .. } => None
+        }).collect();
+
+        if failed_msgs.is_empty() { builder.matched() }
+        else { builder.failed_because(&failed_msgs.join("\n")) }
+    })
+}
+
+/// Matches if the map-like collection has as many entries as the `other` collection.
+///
+/// Only the number of entries is compared, the keys and values may differ.
+/// The entries of `other` are counted when the `Matcher` is created.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// use std::collections::HashMap;
+/// # fn main() {
+/// let mut map = HashMap::<i32,i32>::new();
+/// map.insert(1, 2);
+/// map.insert(2, 5);
+/// let mapped: HashMap<i32,String> = map.iter().map(|(k, v)| (*k, v.to_string())).collect();
+///
+/// assert_that!(&mapped, same_num_entries_as(&map));
+/// # }
+pub fn same_num_entries_as<'a, K: 'a, V: 'a, M: 'a, N>(other: &N) -> Box<Matcher<M> + 'a>
+where for<'all> &'all M: IntoIterator<Item=(&'all K, &'all V)>,
+      for<'all> &'all N: IntoIterator {
+    let expected_count = other.into_iter().count();
+    Box::new(move |map: &M| {
+        let builder = MatchResultBuilder::for_("same_num_entries_as");
+        let actual_count = map.into_iter().count();
+        if actual_count == expected_count {
+            builder.matched()
+        } else {
+            builder.failed_because(
+                &format!("the map has {} entries but the other map has {} entries", actual_count, expected_count)
+            )
+        }
+    })
+}
+
+/// Matches if the map-like collection has the same keys as the `other` collection.
+///
+/// Only the keys are compared, the values may differ.
+/// The keys of `other` are copied when the `Matcher` is created.
+/// On failure the keys present in only one of both collections are reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// use std::collections::HashMap;
+/// # fn main() {
+/// let mut map = HashMap::<&str,i32>::new();
+/// map.insert("foo", 2);
+/// map.insert("bar", 5);
+/// let doubled: HashMap<&str,i32> = map.iter().map(|(k, v)| (*k, v * 2)).collect();
+///
+/// assert_that!(&doubled, has_same_keys_as(&map));
+/// # }
+pub fn has_same_keys_as<'a, K: 'a, V: 'a, W, M: 'a, N>(other: &N) -> Box<Matcher<M> + 'a>
+where K: PartialEq + Clone + Debug,
+      for<'all> &'all M: IntoIterator<Item=(&'all K, &'all V)>,
+      for<'all> &'all N: IntoIterator<Item=(&'all K, &'all W)> {
+    let other_keys: Vec<K> = other.into_iter().map(|(key, _)| key.clone()).collect();
+    Box::new(move |map: &M| {
+        let builder = MatchResultBuilder::for_("has_same_keys_as");
+        let keys: Vec<&K> = map.into_iter().map(|(key, _)| key).collect();
+        let only_in_map: Vec<_> = keys.iter().filter(|key| !other_keys.contains(key)).collect();
+        let only_in_other: Vec<_> = other_keys.iter().filter(|key| !keys.contains(key)).collect();
+
+        if only_in_map.is_empty() && only_in_other.is_empty() {
+            builder.matched()
+        } else {
+            builder.failed_because(
+                &format!("keys only in the map: {:?}, keys only in the other map: {:?}", only_in_map, only_in_other)
+            )
+        }
+    })
+}
+
+/// Matches if the smallest key of the asserted ordered map equals the expected key.
+///
+/// The `Matcher` only accepts `BTreeMap`s as the notion of a smallest key is
+/// not meaningful for maps without an ordered iteration like `HashMap`.
+/// An empty map never satisfies this matcher.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// let mut map = std::collections::BTreeMap::<i32,i32>::new();
+/// map.insert(3, 2);
+/// map.insert(1, 2);
+/// map.insert(2, 5);
+///
+/// assert_that!(&map, has_min_key(1));
+/// # }
+pub fn has_min_key<'a, K:'a, V:'a>(expected: K) -> Box<Matcher<BTreeMap<K,V>> + 'a>
+where K: Ord + Debug {
+    Box::new(move |map: &BTreeMap<K,V>| {
+        let builder = MatchResultBuilder::for_("has_min_key");
+        match map.keys().next() {
+            Some(key) if key == &expected => builder.matched(),
+            Some(key) => builder.failed_comparison(key, &expected),
+            None => builder.failed_because(&format!("the map is empty, expected smallest key {:?}", &expected))
+        }
+    })
+}
+
+/// Matches if the largest key of the asserted ordered map equals the expected key.
+///
+/// The `Matcher` only accepts `BTreeMap`s as the notion of a largest key is
+/// not meaningful for maps without an ordered iteration like `HashMap`.
+/// An empty map never satisfies this matcher.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// let mut map = std::collections::BTreeMap::<i32,i32>::new();
+/// map.insert(3, 2);
+/// map.insert(1, 2);
+/// map.insert(2, 5);
+///
+/// assert_that!(&map, has_max_key(3));
+/// # }
+pub fn has_max_key<'a, K:'a, V:'a>(expected: K) -> Box<Matcher<BTreeMap<K,V>> + 'a>
+where K: Ord + Debug {
+    Box::new(move |map: &BTreeMap<K,V>| {
+        let builder = MatchResultBuilder::for_("has_max_key");
+        match map.keys().next_back() {
+            Some(key) if key == &expected => builder.matched(),
+            Some(key) => builder.failed_comparison(key, &expected),
+            None => builder.failed_because(&format!("the map is empty, expected largest key {:?}", &expected))
+        }
+    })
+}
+
+/// Matches if the element at the given `index` of the asserted collection satisfies the given `Matcher`.
+///
+/// If the collection has no element at `index` the matcher fails.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// assert_that!(&vec![1,2,3], element_at(1, eq(2)));
+/// # }
+pub fn element_at<'a, T: 'a, I: 'a>(index: usize, matcher: Box<Matcher<T> + 'a>) -> Box<Matcher<I> + 'a>
+where for<'all> &'all I: IntoIterator<Item=&'all T> {
+    Box::new(move |elements: &I| {
+        let builder = MatchResultBuilder::for_("element_at");
+        let mut iter = elements.into_iter();
+        match iter.nth(index) {
+            Some(element) => match matcher.check(element) {
+                MatchResult::Failed { name, reason } => builder.failed_because(
+                    &format!("Matcher '{}' failed for the element at index {}:\n{}", name, index, reason)
+                ),
+                matched => matched
+            },
+            None => builder.failed_because(
+                &format!("index {} is out of bounds (len {})", index, elements.into_iter().count())
+            )
+        }
+    })
+}
+
+/// Matches if the first element of the asserted collection satisfies the given `Matcher`.
+///
+/// If the collection is empty the matcher fails.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// assert_that!(&vec![1,2,3], first_is(eq(1)));
+/// # }
+pub fn first_is<'a, T: 'a, I: 'a>(matcher: Box<Matcher<T> + 'a>) -> Box<Matcher<I> + 'a>
+where for<'all> &'all I: IntoIterator<Item=&'all T> {
+    Box::new(move |elements: &I| {
+        let builder = MatchResultBuilder::for_("first_is");
+        match elements.into_iter().next() {
+            Some(element) => match matcher.check(element) {
+                MatchResult::Failed { name, reason } => builder.failed_because(
+                    &format!("Matcher '{}' failed for the first element:\n{}", name, reason)
+                ),
+                matched => matched
+            },
+            None => builder.failed_because("collection is empty")
+        }
+    })
+}
+
+/// Matches if the last element of the asserted collection satisfies the given `Matcher`.
+///
+/// If the collection is empty the matcher fails.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// assert_that!(&vec![1,2,3], last_is(eq(3)));
+/// # }
+pub fn last_is<'a, T: 'a, I: 'a>(matcher: Box<Matcher<T> + 'a>) -> Box<Matcher<I> + 'a>
+where for<'all> &'all I: IntoIterator<Item=&'all T> {
+    Box::new(move |elements: &I| {
+        let builder = MatchResultBuilder::for_("last_is");
+        match elements.into_iter().last() {
+            Some(element) => match matcher.check(element) {
+                MatchResult::Failed { name, reason } => builder.failed_because(
+                    &format!("Matcher '{}' failed for the last element:\n{}", name, reason)
+                ),
+                matched => matched
+            },
+            None => builder.failed_because("collection is empty")
+        }
+    })
+}
+
+/// Matches if the asserted collection contains exactly one element.
+///
+/// On failure the actual number of elements is reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// assert_that!(&vec![42], single());
+/// # }
+pub fn single<'a, T: 'a, I: 'a>() -> Box<Matcher<I> + 'a>
+where for<'all> &'all I: IntoIterator<Item=&'all T> {
+    rename_matcher("single".to_owned(), single_matching(Box::new(|_: &T| MatchResult::from(true))))
+}
+
+/// Matches if the asserted collection contains exactly one element which satisfies the given `Matcher`.
+///
+/// On failure the actual number of elements is reported if it is not exactly one.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// use galvanic_assert::matchers::collection::*;
+/// # fn main() {
+/// assert_that!(&vec![42], single_matching(gt(40)));
+/// # }
+pub fn single_matching<'a, T: 'a, I: 'a>(matcher: Box<Matcher<T> + 'a>) -> Box<Matcher<I> + 'a>
+where for<'all> &'all I: IntoIterator<Item=&'all T> {
+    Box::new(move |elements: &I| {
+        let builder = MatchResultBuilder::for_("single_matching");
+        let mut iter = elements.into_iter();
+        match (iter.next(), iter.next()) {
+            (Some(element), None) => match matcher.check(element) {
+                MatchResult::Failed { name, reason } => builder.failed_because(
+                    &format!("Matcher '{}' failed for the single element:\n{}", name, reason)
+                ),
+                matched => matched
+            },
+            (None, _) => builder.failed_because("expected exactly one element but the collection is empty"),
+            (Some(_), Some(_)) => builder.failed_because(
+                &format!("expected exactly one element but got {}", elements.into_iter().count())
+            )
+        }
+    })
+}
+
+/// Matches if the asserted iterator yields items satisfying the given matchers in order.
+///
+/// The iterator is asserted within a `RefCell` and consumed by the check, so it also works for streaming sources
+/// which cannot be cloned, e.g., `io::Lines` or `mpsc::Iter`.
+/// The i-th `Matcher` is applied to the i-th item of the iterator.
+/// Items are pulled one at a time, so the items are never collected.
+/// The matcher fails fast on the first item not satisfying its `Matcher`
+/// and if the iterator yields less or more items than matchers are given.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// use galvanic_assert::matchers::collection::*;
+/// use std::cell::RefCell;
+/// # fn main() {
+/// assert_that!(&RefCell::new(1..4), yields_in_order(vec![eq(1), lt(3), gt(2)]));
+/// assert_that!(
+///     assert_that!(&RefCell::new(1..5), yields_in_order(vec![eq(1), lt(3), gt(2)])),
+///     panics
+/// );
+/// # }
+pub fn yields_in_order<'a, T: 'a, I: 'a>(matchers: Vec<Box<Matcher<T> + 'a>>) -> Box<Matcher<RefCell<I>> + 'a>
+where T: Debug,
+      I: Iterator<Item=T> {
+    Box::new(move |actual: &RefCell<I>| {
+        let builder = MatchResultBuilder::for_("yields_in_order");
+        let mut items = actual.borrow_mut();
+
+        for (idx, matcher) in matchers.iter().enumerate() {
+            match items.next() {
+                Some(item) => if let MatchResult::Failed { name, reason } = matcher.check(&item) {
+                    return builder.failed_because(
+                        &format!("item {:?} at index {} does not satisfy matcher '{}':\n{}", item, idx, name, reason)
+                    );
+                },
+                None => return builder.failed_because(
+                    &format!("the iterator ended after {} items but {} matchers were given", idx, matchers.len())
+                )
+            }
+        }
+
+        match items.next() {
+            Some(item) => builder.failed_because(
+                &format!("the iterator yields more than {} items, next item is: {:?}", matchers.len(), item)
+            ),
+            None => builder.matched()
+        }
+    })
+}
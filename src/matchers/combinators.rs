@@ -68,17 +68,21 @@ impl<T> All<T> {
     }
 }
 
+/// On success the names of all joined `Matcher`s are composed to `all_of(first, second, ...)`.
 impl<T> Matcher<T> for All<T> {
     fn check(&self, actual: &T) -> MatchResult {
-        match self.matcher.check(actual) {
-            x@MatchResult::Matched {..} => {
-                match self.next {
-                    None => x,
-                    Some(ref next) => next.check(actual)
-                }
-            },
-            x@MatchResult::Failed {..} => x
+        let mut names = Vec::new();
+        let mut current = Some(self);
+        while let Some(all) = current {
+            match all.matcher.check(actual) {
+                MatchResult::Matched { name } => names.push(name),
+                x@MatchResult::Failed {..} => return x
+            }
+            current = all.next.as_ref().map(|next| &**next);
         }
+        // matchers are chained in reverse order of being added
+        names.reverse();
+        MatchResult::Matched { name: format!("all_of({})", names.join(", ")) }
     }
 }
 
@@ -135,10 +139,11 @@ impl<T> Any<T> {
     }
 }
 
+/// On success the name of the `Matcher` which matched is reported.
 impl<T> Matcher<T> for Any<T> {
     fn check(&self, actual: &T) -> MatchResult {
         match self.matcher.check(actual) {
-            MatchResult::Matched {..} => MatchResult::Matched { name: "any_of".to_owned() },
+            x@MatchResult::Matched {..} => x,
             x@MatchResult::Failed {..} => match self.next {
                 None => x,
                 Some(ref next) => next.check(actual)
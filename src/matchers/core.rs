@@ -17,7 +17,13 @@
 //!
 //! The matchers in this module all operate on single values.
 
-use std::fmt::Debug;
+use std::any::Any;
+use std::borrow::Borrow;
+use std::cell::RefCell;
+use std::convert::TryFrom;
+use std::fmt::{Debug, Display};
+use std::thread;
+use std::time::{Duration, Instant};
 use super::super::*;
 
 macro_rules! matchresult_from_comparison {
@@ -139,6 +145,203 @@ pub fn not<'a, T: 'a>(matcher: Box<Matcher<T> + 'a>) -> Box<Matcher<T> + 'a> {
     })
 }
 
+/// Matches the value returned by `projection` for the asserted value against the passed `Matcher`.
+///
+/// The `projection` may return an owned value, e.g., the result of a getter like `len()`.
+/// The returned value is stored for the duration of the check and the `Matcher` is applied to a reference of it.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// # fn main() {
+/// assert_that!(&vec![1, 2, 3], returning(|v: &Vec<i32>| v.len(), eq(3)));
+/// # }
+pub fn returning<'a, T: 'a, U: 'a, F: 'a>(projection: F, matcher: Box<Matcher<U> + 'a>) -> Box<Matcher<T> + 'a>
+where F: Fn(&T) -> U {
+    Box::new(move |actual: &T| {
+        let returned = projection(actual);
+        matcher.check(&returned)
+    })
+}
+
+/// Matches the value obtained by applying `transform` to the asserted value against the passed `Matcher`.
+///
+/// This is `returning` with a failure reason noting that the transformation was applied and showing the transformed value.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// # fn main() {
+/// assert_that!(&-5, after(|x: &i32| x.abs(), eq(5)));
+/// assert_that!(&"foo", after(|s: &&str| s.to_uppercase(), eq("FOO".to_owned())));
+/// # }
+pub fn after<'a, T: 'a, U: 'a, F: 'a>(transform: F, matcher: Box<Matcher<U> + 'a>) -> Box<Matcher<T> + 'a>
+where F: Fn(&T) -> U, U: Debug {
+    returning(transform, Box::new(move |transformed: &U| match matcher.check(transformed) {
+        x@MatchResult::Matched { .. } => x,
+        MatchResult::Failed { name, reason } =>
+            MatchResultBuilder::for_(&format!("after({})", name))
+                               .failed_because(&format!("after transformation to {:?}: {}", transformed, reason))
+    }))
+}
+
+/// Matches a mutated clone of the asserted value against the passed `Matcher`.
+///
+/// The asserted value is cloned and `mutate` is applied to the clone, the asserted value itself is left untouched.
+/// On failure the value after the mutation is reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// # fn main() {
+/// let values = vec![3, 1, 2];
+/// assert_that!(&values, cloned_then(|v: &mut Vec<i32>| v.sort(), eq(vec![1, 2, 3])));
+/// assert_that!(&values, eq(vec![3, 1, 2]));
+/// # }
+pub fn cloned_then<'a, T: 'a, F: 'a>(mutate: F, matcher: Box<Matcher<T> + 'a>) -> Box<Matcher<T> + 'a>
+where F: Fn(&mut T), T: Clone + Debug {
+    Box::new(move |actual: &T| {
+        let mut mutated = actual.clone();
+        mutate(&mut mutated);
+        match matcher.check(&mutated) {
+            x@MatchResult::Matched { .. } => x,
+            MatchResult::Failed { name, reason } =>
+                MatchResultBuilder::for_(&format!("cloned_then({})", name))
+                                   .failed_because(&format!("after mutation to {:?}: {}", mutated, reason))
+        }
+    })
+}
+
+/// Repeatedly checks the value produced by the asserted closure against the passed `Matcher` until it matches or the `timeout` elapses.
+///
+/// The asserted value is a closure producing the value to be checked, so changing state can be observed.
+/// The closure is called again after each `poll_interval` if the `Matcher` failed.
+/// If the `Matcher` did not match before the `timeout` elapsed, the last failure is reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// use std::cell::Cell;
+/// use std::time::Duration;
+/// # fn main() {
+/// let polls = Cell::new(0);
+/// let poll = || { polls.set(polls.get() + 1); polls.get() };
+/// assert_that!(&poll, eventually(eq(3), Duration::from_secs(1), Duration::from_millis(1)));
+/// # }
+pub fn eventually<'a, T: 'a, F: 'a>(matcher: Box<Matcher<T> + 'a>, timeout: Duration, poll_interval: Duration) -> Box<Matcher<F> + 'a>
+where F: Fn() -> T {
+    Box::new(move |producer: &F| {
+        let start = Instant::now();
+        loop {
+            match matcher.check(&producer()) {
+                MatchResult::Matched { .. } => return MatchResultBuilder::for_("eventually").matched(),
+                MatchResult::Failed { name, reason } => if start.elapsed() >= timeout {
+                    return MatchResultBuilder::for_("eventually").failed_because(
+                        &format!("Matcher '{}' did not match within {:?}, last failure:\n{}", name, timeout, reason)
+                    );
+                }
+            }
+            thread::sleep(poll_interval);
+        }
+    })
+}
+
+/// Checks the value produced by the asserted closure against the passed `Matcher` but produces the value only once.
+///
+/// The asserted value is a closure producing the value to be checked, e.g., an expensive or side-effecting accessor.
+/// The closure is called once per check and the produced value is passed to the `Matcher`.
+/// When combining matchers within `once`, e.g., `once(all_of!(...))`, all of them inspect the same produced value.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// use std::cell::Cell;
+/// # fn main() {
+/// let calls = Cell::new(0);
+/// let expensive = || { calls.set(calls.get() + 1); 42 };
+/// assert_that!(&expensive, once(all_of!(gt(40), lt(50))));
+/// assert_that!(&calls.get(), eq(1));
+/// # }
+pub fn once<'a, T: 'a, F: 'a>(matcher: Box<Matcher<T> + 'a>) -> Box<Matcher<F> + 'a>
+where F: Fn() -> T {
+    Box::new(move |producer: &F| {
+        let value = producer();
+        matcher.check(&value)
+    })
+}
+
+/// Matches if the asserted closure produces the same value each time it is called.
+///
+/// The closure is called `times` times and all produced values are compared to the first one.
+/// On failure all produced values which differ from the first one are reported.
+/// Instead of passing a closure, an expression can be asserted with `assert_that!(EXPRESSION, is deterministic(times))`.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// # fn main() {
+/// assert_that!(&|| "foo".len(), deterministic(10));
+/// assert_that!("foo".to_uppercase(), is deterministic(10));
+/// # }
+pub fn deterministic<'a, T: 'a, F: 'a>(times: usize) -> Box<Matcher<F> + 'a>
+where F: Fn() -> T, T: PartialEq + Debug {
+    Box::new(move |producer: &F| {
+        let builder = MatchResultBuilder::for_("deterministic");
+        if times == 0 {
+            return builder.matched();
+        }
+
+        let first = producer();
+        let differing: Vec<_> = (1..times).map(|run| (run, producer()))
+                                          .filter(|&(_, ref value)| value != &first)
+                                          .collect();
+        if differing.is_empty() {
+            builder.matched()
+        } else {
+            builder.failed_because(
+                &format!("the first run produced {:?} but the following (run, value) pairs differ: {:?}", first, differing)
+            )
+        }
+    })
+}
+
+/// Checks the passed matcher twice and fails if both checks produce different results.
+///
+/// Matchers must be repeatably callable, e.g., to be combined with other matchers.
+/// This is a debugging aid for authors of custom matchers to detect accidentally stateful matchers.
+/// If both results are equal the result is passed on unmodified.
+/// The second check is only performed if `debug_assertions` are enabled, otherwise the passed matcher is checked once.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// # fn main() {
+/// assert_that!(&(1+1), idempotent_matcher(eq(2)));
+/// # }
+pub fn idempotent_matcher<'a, T: 'a>(matcher: Box<Matcher<T> + 'a>) -> Box<Matcher<T> + 'a> {
+    Box::new(move |actual: &T| {
+        let first = matcher.check(actual);
+        if !cfg!(debug_assertions) {
+            return first;
+        }
+        let second = matcher.check(actual);
+        if first == second {
+            first
+        } else {
+            MatchResultBuilder::for_("idempotent_matcher").failed_because(
+                &format!("repeated checks of the matcher diverged\n\tFirst: {:?}\n\tSecond: {:?}", first, second)
+            )
+        }
+    })
+}
+
 /// Matches if the asserted value is equal to the expected value.
 ///
 /// This matcher should not be used when asserting floating point values.
@@ -169,6 +372,60 @@ where T: PartialEq + Debug + 'a {
 /// # }
 pub fn eq<'a, T: PartialEq + Debug + 'a>(expected: T) -> Box<Matcher<T> + 'a> { equal_to(expected) }
 
+/// Matches if the asserted value is equal to the value returned by `expected`.
+///
+/// The expected value is computed lazily each time the `Matcher` is checked instead of when it is created.
+/// This allows to compare against state which changes between the creation of the `Matcher` and the assertion.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// use std::cell::Cell;
+/// # fn main() {
+/// let counter = Cell::new(1);
+/// let matcher = equal_to_fn(|| counter.get());
+/// counter.set(2);
+/// assert_that!(&2, matcher);
+/// # }
+pub fn equal_to_fn<'a, T, F>(expected: F) -> Box<Matcher<T> + 'a>
+where T: PartialEq + Debug + 'a,
+      F: Fn() -> T + 'a {
+    Box::new(move |actual: &T| {
+        let expected = expected();
+        matchresult_from_comparison!(actual == expected, "equal_to_fn")
+    })
+}
+
+/// Matches if the asserted value is equal to the value derived from `source` by applying `derive`.
+///
+/// The expected value is derived each time the `Matcher` is checked.
+/// On failure the `source`, the derived expected value, and the asserted value are reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// # fn main() {
+/// let items = vec![1, 2, 3];
+/// let cached_length = 3;
+/// assert_that!(&cached_length, equal_to_derived(&items, |v: &&Vec<i32>| v.len()));
+/// # }
+pub fn equal_to_derived<'a, S, T, F>(source: S, derive: F) -> Box<Matcher<T> + 'a>
+where S: Debug + 'a,
+      T: PartialEq + Debug + 'a,
+      F: Fn(&S) -> T + 'a {
+    Box::new(move |actual: &T| {
+        let builder = MatchResultBuilder::for_("equal_to_derived");
+        let expected = derive(&source);
+        if actual == &expected {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} is not equal to {:?} derived from {:?}", actual, expected, source))
+        }
+    })
+}
+
 /// Matches if the asserted value is less than the expected value.
 ///
 /// This is the same as [lt].
@@ -310,6 +567,623 @@ where T: Copy + PartialOrd + std::ops::Add<Output=T> + std::ops::Sub<Output=T> +
     })
 }
 
+/// Matches if the distance between the asserted value and the expected value is at most `max_distance`.
+///
+/// The distance is computed by the given `metric`, which generalizes [close_to] to non-scalar values, e.g., points or colors.
+/// A distance of `NaN` never satisfies this matcher. On failure the computed distance is reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// # fn main() {
+/// let euclidean = |a: &(f64, f64), b: &(f64, f64)| ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt();
+/// assert_that!(&(3.0, 4.01), close_by((3.0, 4.0), euclidean, 0.1));
+/// # }
+pub fn close_by<'a, T, F>(expected: T, metric: F, max_distance: f64) -> Box<Matcher<T> + 'a>
+where T: Debug + 'a,
+      F: Fn(&T, &T) -> f64 + 'a {
+    Box::new(move |actual: &T| {
+        let builder = MatchResultBuilder::for_("close_by");
+        let distance = metric(actual, &expected);
+        if distance <= max_distance {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("the distance between {:?} and {:?} is {} which exceeds {}",
+                                            actual, expected, distance, max_distance)
+            )
+        }
+    })
+}
+
+/// Matches if the asserted value lies within any of the given inclusive ranges.
+///
+/// Each range is given as a pair `(lower, upper)` of inclusive bounds.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// # fn main() {
+/// assert_that!(&8080, in_any_range(vec![(80, 80), (443, 443), (8000, 8999)]));
+/// # }
+pub fn in_any_range<'a, T>(ranges: Vec<(T, T)>) -> Box<Matcher<T> + 'a>
+where T: PartialOrd + Debug + 'a {
+    Box::new(move |actual: &T| {
+        let builder = MatchResultBuilder::for_("in_any_range");
+        if ranges.iter().any(|&(ref lower, ref upper)| lower <= actual && actual <= upper) {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} is not within any of the ranges {:?}", actual, ranges))
+        }
+    })
+}
+
+/// Matches if the asserted value lies between `low` and `high` inclusively.
+///
+/// The bounds can be given as values or as references to other values, e.g., bounds computed in the test.
+/// On failure the asserted value and both bounds are reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// # fn main() {
+/// let lower = 0.5 * 10.0;
+/// let upper = 2.0 * 10.0;
+/// assert_that!(&12.5, between_values(&lower, &upper));
+/// assert_that!(&7, between_values(7, 9));
+/// # }
+pub fn between_values<'a, T, L, H>(low: L, high: H) -> Box<Matcher<T> + 'a>
+where T: PartialOrd + Debug + 'a,
+      L: Borrow<T> + 'a,
+      H: Borrow<T> + 'a {
+    Box::new(move |actual: &T| {
+        let builder = MatchResultBuilder::for_("between_values");
+        let (low, high) = (low.borrow(), high.borrow());
+        if low <= actual && actual <= high {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} is not between {:?} and {:?}", actual, low, high))
+        }
+    })
+}
+
+/// Signed numeric types whose absolute value can be computed without panicking on overflow, used by `abs_less_than`.
+pub trait CheckedAbs: Sized {
+    /// Returns the absolute value or `None` if it is not representable by the type.
+    fn checked_absolute(&self) -> Option<Self>;
+}
+
+macro_rules! checked_abs_for_integers {
+    ( $($int:ty),* ) => {
+        $(
+        impl CheckedAbs for $int {
+            fn checked_absolute(&self) -> Option<$int> { self.checked_abs() }
+        }
+        )*
+    }
+}
+
+checked_abs_for_integers!(i8, i16, i32, i64, i128, isize);
+
+impl CheckedAbs for f32 {
+    fn checked_absolute(&self) -> Option<f32> { Some(self.abs()) }
+}
+
+impl CheckedAbs for f64 {
+    fn checked_absolute(&self) -> Option<f64> { Some(self.abs()) }
+}
+
+/// Matches if the absolute value of the asserted signed number is less than `bound`.
+///
+/// The `bound` must be positive, otherwise the matcher fails.
+/// If the absolute value is not representable by the type, e.g., for `i32::min_value()`, the matcher fails as well.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// # fn main() {
+/// assert_that!(&-0.001, abs_less_than(0.01));
+/// assert_that!(&3, abs_less_than(4));
+/// # }
+pub fn abs_less_than<'a, T>(bound: T) -> Box<Matcher<T> + 'a>
+where T: Copy + Default + PartialOrd + CheckedAbs + Debug + 'a {
+    Box::new(move |actual: &T| {
+        let builder = MatchResultBuilder::for_("abs_less_than");
+        if bound.partial_cmp(&T::default()) != Some(std::cmp::Ordering::Greater) {
+            return builder.failed_because(&format!("the bound {:?} is not positive", bound));
+        }
+
+        match actual.checked_absolute() {
+            Some(magnitude) => if magnitude < bound {
+                builder.matched()
+            } else {
+                builder.failed_because(
+                    &format!("the absolute value of {:?} is {:?} which is not less than {:?}", actual, magnitude, bound)
+                )
+            },
+            None => builder.failed_because(
+                &format!("the absolute value of {:?} is not representable and not less than {:?}", actual, bound)
+            )
+        }
+    })
+}
+
+/// Matches if the `Ord` implementation of the asserted value is consistent when compared with `other`.
+///
+/// Both values must compare `Equal` to themselves, `actual.cmp(&other)` must be the reverse of `other.cmp(&actual)`,
+/// and the result must agree with `PartialEq` and `PartialOrd`.
+/// On failure the first violated property is reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// # fn main() {
+/// assert_that!(&"apple", consistent_ordering("banana"));
+/// assert_that!(&(1, 'b'), consistent_ordering((1, 'b')));
+/// # }
+pub fn consistent_ordering<'a, T>(other: T) -> Box<Matcher<T> + 'a>
+where T: Ord + Debug + 'a {
+    Box::new(move |actual: &T| {
+        let builder = MatchResultBuilder::for_("consistent_ordering");
+        for value in &[actual, &other] {
+            if value.cmp(value) != std::cmp::Ordering::Equal {
+                return builder.failed_because(
+                    &format!("{:?} compares {:?} to itself", value, value.cmp(value))
+                );
+            }
+        }
+
+        let forward = actual.cmp(&other);
+        let backward = other.cmp(actual);
+        if forward != backward.reverse() {
+            builder.failed_because(
+                &format!("{:?} compares {:?} to {:?} but {:?} compares {:?} to {:?}",
+                         actual, forward, other, other, backward, actual)
+            )
+        } else if (forward == std::cmp::Ordering::Equal) != (*actual == other) {
+            builder.failed_because(
+                &format!("{:?} compares {:?} to {:?} but equality yields {}",
+                         actual, forward, other, *actual == other)
+            )
+        } else if actual.partial_cmp(&other) != Some(forward) {
+            builder.failed_because(
+                &format!("{:?} compares {:?} to {:?} but partial_cmp yields {:?}",
+                         actual, forward, other, actual.partial_cmp(&other))
+            )
+        } else { builder.matched() }
+    })
+}
+
+/// Floating point types which can be rounded by `rounds_to`, `floors_to` and `ceils_to`.
+pub trait RoundableFloat {
+    /// Returns the value losslessly converted to `f64`.
+    fn as_f64(&self) -> f64;
+}
+
+impl RoundableFloat for f32 {
+    fn as_f64(&self) -> f64 { *self as f64 }
+}
+
+impl RoundableFloat for f64 {
+    fn as_f64(&self) -> f64 { *self }
+}
+
+fn rounded_with<'a, T, R: 'a>(name: &'static str, expected: i64, rounding: R) -> Box<Matcher<T> + 'a>
+where T: RoundableFloat + Debug + 'a,
+      R: Fn(f64) -> f64 {
+    // the bounds of `i64` are powers of two and thus exactly representable as `f64`
+    const I64_LOWER: f64 = -9223372036854775808.0;
+    const I64_UPPER: f64 = 9223372036854775808.0;
+
+    Box::new(move |actual: &T| {
+        let builder = MatchResultBuilder::for_(name);
+        let rounded = rounding(actual.as_f64());
+        if rounded.is_nan() {
+            return builder.failed_because(&format!("{:?} cannot be rounded to an integer but expected {}", actual, expected));
+        }
+        if !(I64_LOWER..I64_UPPER).contains(&rounded) {
+            return builder.failed_because(&format!("{:?} is rounded to {} which is outside of the range of i64 but expected {}",
+                                                   actual, rounded, expected));
+        }
+
+        let rounded = rounded as i64;
+        if rounded == expected {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} is rounded to {} but expected {}",
+                                            actual, rounded, expected)
+            )
+        }
+    })
+}
+
+/// Matches if the asserted floating point value rounds to the expected integer.
+///
+/// Halfway cases are rounded away from zero, see `f64::round`.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// # fn main() {
+/// assert_that!(&2.5, rounds_to(3));
+/// assert_that!(&-2.4, rounds_to(-2));
+/// # }
+pub fn rounds_to<'a, T>(expected: i64) -> Box<Matcher<T> + 'a>
+where T: RoundableFloat + Debug + 'a {
+    rounded_with("rounds_to", expected, f64::round)
+}
+
+/// Matches if the asserted floating point value rounds down to the expected integer.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// # fn main() {
+/// assert_that!(&2.9, floors_to(2));
+/// assert_that!(&-2.1, floors_to(-3));
+/// # }
+pub fn floors_to<'a, T>(expected: i64) -> Box<Matcher<T> + 'a>
+where T: RoundableFloat + Debug + 'a {
+    rounded_with("floors_to", expected, f64::floor)
+}
+
+/// Matches if the asserted floating point value rounds up to the expected integer.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// # fn main() {
+/// assert_that!(&2.1, ceils_to(3));
+/// assert_that!(&-2.9, ceils_to(-2));
+/// # }
+pub fn ceils_to<'a, T>(expected: i64) -> Box<Matcher<T> + 'a>
+where T: RoundableFloat + Debug + 'a {
+    rounded_with("ceils_to", expected, f64::ceil)
+}
+
+/// Floating point types whose raw bit pattern can be compared by `total_eq`.
+pub trait FloatBits {
+    /// The unsigned integer type holding the bit pattern.
+    type Bits: PartialEq + std::fmt::LowerHex;
+    /// Returns the raw bit pattern of the value.
+    fn float_bits(&self) -> Self::Bits;
+}
+
+impl FloatBits for f32 {
+    type Bits = u32;
+    fn float_bits(&self) -> u32 { self.to_bits() }
+}
+
+impl FloatBits for f64 {
+    type Bits = u64;
+    fn float_bits(&self) -> u64 { self.to_bits() }
+}
+
+/// Matches if the asserted floating point value has the same bit pattern as the expected value.
+///
+/// In contrast to IEEE comparison `NaN` equals `NaN` if the payloads are equal and `+0.0` differs from `-0.0`.
+/// This is useful to assert that floating point values are preserved exactly, e.g., by a round-trip.
+/// On failure both bit patterns are reported in hexadecimal.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// use std::f64;
+/// # fn main() {
+/// assert_that!(&f64::NAN, total_eq(f64::NAN));
+/// assert_that!(&0.0, not(total_eq(-0.0)));
+/// # }
+pub fn total_eq<'a, T>(expected: T) -> Box<Matcher<T> + 'a>
+where T: FloatBits + Debug + 'a {
+    Box::new(move |actual: &T| {
+        let builder = MatchResultBuilder::for_("total_eq");
+        let actual_bits = actual.float_bits();
+        let expected_bits = expected.float_bits();
+        if actual_bits == expected_bits {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} (bits {:#x}) is not bitwise equal to {:?} (bits {:#x})",
+                                            actual, actual_bits, expected, expected_bits)
+            )
+        }
+    })
+}
+
+/// Matches if the asserted floating point value has no fractional part.
+///
+/// Infinite values and `NaN` are never integral.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// # fn main() {
+/// assert_that!(&3.0, is_integral());
+/// assert_that!(&-2.0f32, is_integral());
+/// # }
+pub fn is_integral<'a, T>() -> Box<Matcher<T> + 'a>
+where T: Copy + Into<f64> + Debug + 'a {
+    Box::new(move |actual: &T| {
+        let builder = MatchResultBuilder::for_("is_integral");
+        let fraction = (*actual).into().fract();
+        if fraction == 0.0 {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} has the fractional part {}", actual, fraction))
+        }
+    })
+}
+
+/// Matches if applying `f` to the asserted value yields a finite result, i.e., neither infinite nor `NaN`.
+///
+/// On failure the asserted value and the non-finite result are reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// # fn main() {
+/// assert_that!(&1e-300, maps_to_finite(|x: &f64| x.ln()));
+/// assert_that!(
+///     assert_that!(&0.0, maps_to_finite(|x: &f64| 1.0 / x)),
+///     panics
+/// );
+/// # }
+pub fn maps_to_finite<'a, T, F>(f: F) -> Box<Matcher<T> + 'a>
+where T: Debug + 'a,
+      F: Fn(&T) -> f64 + 'a {
+    Box::new(move |actual: &T| {
+        let builder = MatchResultBuilder::for_("maps_to_finite");
+        let result = f(actual);
+        if result.is_finite() {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} is mapped to the non-finite value {}", actual, result))
+        }
+    })
+}
+
+/// Matches if the shortest decimal representation of the asserted floating point value has `expected` significant digits.
+///
+/// The value is rendered with `Display`, leading and trailing zeros are not significant, e.g., `0.0250` and `1200.0` both have 2.
+/// Zero has a single significant digit. Infinite values and `NaN` never satisfy this matcher.
+/// On failure the rendered value and its actual number of significant digits are reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// # fn main() {
+/// assert_that!(&3.14, has_significant_digits(3));
+/// assert_that!(&0.00012f32, has_significant_digits(2));
+/// # }
+pub fn has_significant_digits<'a, T>(expected: usize) -> Box<Matcher<T> + 'a>
+where T: Copy + Into<f64> + Display + 'a {
+    Box::new(move |actual: &T| {
+        let builder = MatchResultBuilder::for_("has_significant_digits");
+        let rendered = format!("{}", actual);
+        if !(*actual).into().is_finite() {
+            return builder.failed_because(&format!("{} is not finite", rendered));
+        }
+        let digits: String = rendered.chars().filter(|c| c.is_ascii_digit()).collect();
+        let significant = match digits.trim_matches('0').len() {
+            0 => 1,
+            n => n
+        };
+        if significant == expected {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{} has {} significant digits but expected {}", rendered, significant, expected))
+        }
+    })
+}
+
+/// Matches if the asserted `Duration` differs from the expected `Duration` by at most the given `tolerance`.
+///
+/// The absolute difference is computed without overflowing, regardless of which `Duration` is larger.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// use std::time::Duration;
+/// # fn main() {
+/// assert_that!(&Duration::from_millis(103), duration_close_to(Duration::from_millis(100), Duration::from_millis(5)));
+/// assert_that!(&Duration::from_millis(97), duration_close_to(Duration::from_millis(100), Duration::from_millis(5)));
+/// # }
+pub fn duration_close_to<'a>(expected: Duration, tolerance: Duration) -> Box<Matcher<Duration> + 'a> {
+    Box::new(move |actual: &Duration| {
+        let builder = MatchResultBuilder::for_("duration_close_to");
+        let difference = if *actual > expected { *actual - expected } else { expected - *actual };
+        if difference <= tolerance {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} differs from {:?} by {:?} which exceeds the tolerance of {:?}",
+                                            actual, expected, difference, tolerance)
+            )
+        }
+    })
+}
+
+/// Matches if the asserted `Duration` is not zero.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// use std::time::Duration;
+/// # fn main() {
+/// assert_that!(&Duration::from_nanos(1), is_nonzero_duration());
+/// # }
+pub fn is_nonzero_duration<'a>() -> Box<Matcher<Duration> + 'a> {
+    Box::new(|actual: &Duration| {
+        let builder = MatchResultBuilder::for_("is_nonzero_duration");
+        if *actual > Duration::new(0, 0) {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("the duration is {:?} but expected a positive duration", actual))
+        }
+    })
+}
+
+/// Checks that asserted values are monotone relative to the value seen by the previous check.
+///
+/// The last checked value is stored inside of the checker, so separate assertions can be made
+/// against the same checker, e.g., for streaming metrics which are observed one at a time.
+/// The stored value is replaced on each check regardless of the result. The first check always matches.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::Matcher;
+/// use galvanic_assert::matchers::*;
+/// # fn main() {
+/// let checker = MonotoneChecker::non_decreasing();
+/// assert_that!(&0.5, &checker);
+/// assert_that!(&0.5, &checker);
+/// assert_that!(&1.5, &checker);
+/// // the checker is not unwind safe, so check directly instead of asserting a panic
+/// assert_that!(&bool::from(checker.check(&1.0)), eq(false));
+/// # }
+pub struct MonotoneChecker<T> {
+    last: RefCell<Option<T>>,
+    strict: bool
+}
+
+impl<T> MonotoneChecker<T> {
+    /// Creates a checker which fails if a value is not strictly greater than the previous one.
+    pub fn increasing() -> MonotoneChecker<T> {
+        MonotoneChecker { last: RefCell::new(None), strict: true }
+    }
+
+    /// Creates a checker which fails if a value is less than the previous one.
+    pub fn non_decreasing() -> MonotoneChecker<T> {
+        MonotoneChecker { last: RefCell::new(None), strict: false }
+    }
+}
+
+impl<T> Matcher<T> for MonotoneChecker<T>
+where T: PartialOrd + Clone + Debug {
+    fn check(&self, actual: &T) -> MatchResult {
+        let builder = MatchResultBuilder::for_(if self.strict { "increasing" } else { "non_decreasing" });
+        let previous = self.last.replace(Some(actual.clone()));
+        match previous {
+            None => builder.matched(),
+            Some(ref previous) if self.strict && actual > previous => builder.matched(),
+            Some(ref previous) if !self.strict && actual >= previous => builder.matched(),
+            Some(previous) => builder.failed_because(
+                &format!("{:?} is not {} the previous value {:?}",
+                         actual, if self.strict { "greater than" } else { "greater than or equal to" }, previous)
+            )
+        }
+    }
+}
+
+/// Matches if the asserted integer is an exact power of two.
+///
+/// Zero and negative values are never a power of two.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// # fn main() {
+/// assert_that!(&64usize, is_power_of_two());
+/// assert_that!(&1u8, is_power_of_two());
+/// # }
+pub fn is_power_of_two<'a, T>() -> Box<Matcher<T> + 'a>
+where T: Copy + PartialOrd + From<u8> + std::ops::BitAnd<Output=T> + std::ops::Sub<Output=T> + Debug + 'a {
+    Box::new(move |actual: &T| {
+        let builder = MatchResultBuilder::for_("is_power_of_two");
+        let zero = T::from(0);
+        if *actual > zero && (*actual & (*actual - T::from(1))) == zero {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} is not a power of two", actual))
+        }
+    })
+}
+
+/// Matches if the given `bit` of the asserted integer is set.
+///
+/// Bits are counted from the least significant bit starting at zero.
+/// Bits beyond the width of the integer type are never set.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// # fn main() {
+/// assert_that!(&0b0100u8, has_bit_set(2));
+/// # }
+pub fn has_bit_set<'a, T>(bit: u32) -> Box<Matcher<T> + 'a>
+where T: Copy + PartialEq + From<u8> + std::ops::BitAnd<Output=T> + std::ops::Shl<u32, Output=T>
+       + std::fmt::Binary + std::fmt::LowerHex + 'a {
+    Box::new(move |actual: &T| {
+        let builder = MatchResultBuilder::for_("has_bit_set");
+        let width = (std::mem::size_of::<T>() * 8) as u32;
+        if bit < width && (*actual & (T::from(1) << bit)) != T::from(0) {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("bit {} is not set in {:#b} ({:#x})", bit, actual, actual))
+        }
+    })
+}
+
+/// Matches if all bits of the `mask` are set in the asserted integer.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// # fn main() {
+/// assert_that!(&0b1101u8, has_flags(0b0101));
+/// # }
+pub fn has_flags<'a, T>(mask: T) -> Box<Matcher<T> + 'a>
+where T: Copy + PartialEq + std::ops::BitAnd<Output=T> + std::fmt::Binary + std::fmt::LowerHex + 'a {
+    Box::new(move |actual: &T| {
+        let builder = MatchResultBuilder::for_("has_flags");
+        if (*actual & mask) == mask {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:#b} ({:#x}) does not have all flags of the mask {:#b} ({:#x}) set",
+                                            actual, actual, mask, mask))
+        }
+    })
+}
+
+/// Matches if the asserted integer can be converted to the integer type `U` without loss.
+///
+/// The conversion is checked with `TryFrom`, so the value has to lie within the range of `U`.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// # fn main() {
+/// assert_that!(&255u64, fits_in::<u8, _>());
+/// assert_that!(&-1i32, not(fits_in::<u32, _>()));
+/// # }
+pub fn fits_in<'a, U, T>() -> Box<Matcher<T> + 'a>
+where U: TryFrom<T>, T: Copy + Debug + 'a {
+    Box::new(|actual: &T| {
+        let builder = MatchResultBuilder::for_("fits_in");
+        if U::try_from(*actual).is_ok() {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} does not fit in {}", actual, std::any::type_name::<U>()))
+        }
+    })
+}
+
 /// Matches if asserted value and the expected value are truely the same object.
 ///
 /// The two values are the same if the reside at the same memory address.
@@ -343,10 +1217,42 @@ where T: Debug + 'a {
     })
 }
 
+/// Matches if the dynamic type of the asserted `Any` value is the concrete type `C`.
+///
+/// The asserted value must be borrowable as `Any`, e.g., `&Any` or `Box<Any>`,
+/// the check is done with `downcast_ref`. Values behind other trait objects need to be converted
+/// to `Any` first as the dynamic type cannot be inspected otherwise.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// use std::any::Any;
+/// # fn main() {
+/// let boxed: Box<Any> = Box::new(42i32);
+/// assert_that!(&boxed, is_type::<i32, _>());
+/// assert_that!(&boxed, not(is_type::<String, _>()));
+/// # }
+pub fn is_type<'a, C, T>() -> Box<Matcher<T> + 'a>
+where C: Any, T: Borrow<Any> + 'a {
+    Box::new(|actual: &T| {
+        let builder = MatchResultBuilder::for_("is_type");
+        if actual.borrow().downcast_ref::<C>().is_some() {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("the dynamic type of the value is not {}", std::any::type_name::<C>()))
+        }
+    })
+}
+
 /// Write patterns of structs/enums which use `Matcher`s instead of field values.
 ///
 /// When providing matchers for multiple fields, not that *all* matchers will be evaluated.
-/// Even if one of them returns `MatchResult::Failed`.
+/// Even if one of them returns `MatchResult::Failed`. Use `matches_fields!` to stop
+/// evaluating further fields after a failed one.
+///
+/// Structural matchers can be nested. Failure messages then contain the full dotted path
+/// of the failing field, e.g., `outer.inner.x`.
 ///
 /// For struct-like structs/enum-variants not all fields need to be listed in the pattern.
 /// Unwanted fields can safely be ommitted. For tuple-like structs/enum-variants all fields
@@ -393,16 +1299,118 @@ where T: Debug + 'a {
 /// # }
 #[macro_export]
 macro_rules! has_structure {
-    ( $variant:path { $( $field:ident : $matcher:expr ),* $(,)* } ) => { structure!($variant { $($field : $matcher),* }) };
-    ( $variant:path [ $( $matchers:expr ),* ] ) => { structure!($variant [ $($matchers),* ]) }
+    ( $($pattern:tt)* ) => { structure!($($pattern)*) }
 }
+
+/// Write patterns of structs/enums like `has_structure!` but choose for each field whether a failure stops the evaluation.
+///
+/// Each field's `Matcher` can be followed by a single mode, either `=> fatal` or `=> collect`.
+/// If a `fatal` field fails, the remaining fields are not evaluated and all failures so far are reported.
+/// Failures of `collect` fields are collected and the evaluation continues, this is the default if no mode is given.
+/// The `collect` mode only exists to state the default explicitly, e.g., next to `fatal` fields.
+/// This allows to skip expensive field checks if a cheap precondition already failed.
+///
+/// Only struct-like structs/enum-variants are supported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// # fn main() {
+/// struct Response { status: u16, body: String }
+/// let response = Response { status: 200, body: "ok".to_owned() };
+/// assert_that!(&response, matches_fields!(Response {
+///     status: eq(200) => fatal,
+///     body: eq("ok".to_owned())
+/// }));
+/// # }
+#[macro_export]
+macro_rules! matches_fields {
+    (@fields $variant:path ; [ $( ($field:ident ; $matcher:expr ; $($mode:ident)*) )* ] ) => {
+        Box::new(|actual: &_| {
+            use galvanic_assert::{MatchResultBuilder, MatchResult};
+            let builder = MatchResultBuilder::for_("matches_fields");
+            #[allow(unreachable_patterns)]
+            match actual {
+                &$variant { $( ref $field, )* ..} => {
+                    let mut failed_msgs = Vec::new();
+                    $(
+                        if let MatchResult::Failed{ name, reason } = $matcher.check($field) {
+                            let path = stringify!($field);
+                            failed_msgs.push(
+                                format!("Matcher '{}' for field '{}' at {}:{} failed:\n\t{}",
+                                        name, path, file!().to_string(), line!(), reason)
+                            );
+                            matches_fields!(@on_failure $($mode)* ; builder ; failed_msgs ; path);
+                        }
+                    )*
+                    if failed_msgs.is_empty() { builder.matched() }
+                    else { builder.failed_because(&failed_msgs.join("\n")) }
+                },
+                _ => builder.failed_because(
+                        &format!("passed variant does not match '{}'", stringify!($variant))
+                )
+            }
+        })
+    };
+    (@fields $variant:path ; [ $($done:tt)* ] $field:ident : has_structure! $inner:tt $( => $mode:ident )? , $($rest:tt)* ) => {
+        matches_fields!(@fields $variant ; [ $($done)* ] $field : structure! $inner $( => $mode )? , $($rest)*)
+    };
+    (@fields $variant:path ; [ $($done:tt)* ] $field:ident : has_structure! $inner:tt $( => $mode:ident )? ) => {
+        matches_fields!(@fields $variant ; [ $($done)* ] $field : structure! $inner $( => $mode )?)
+    };
+    (@fields $variant:path ; [ $($done:tt)* ] $field:ident : structure! $inner:tt $( => $mode:ident )? , $($rest:tt)* ) => {
+        matches_fields!(@fields $variant ; [ $($done)* ($field ; structure!(@nested [$field] $inner) ; $($mode)*) ] $($rest)*)
+    };
+    (@fields $variant:path ; [ $($done:tt)* ] $field:ident : structure! $inner:tt $( => $mode:ident )? ) => {
+        matches_fields!(@fields $variant ; [ $($done)* ($field ; structure!(@nested [$field] $inner) ; $($mode)*) ])
+    };
+    (@fields $variant:path ; [ $($done:tt)* ] $field:ident : $matcher:expr $( => $mode:ident )? , $($rest:tt)* ) => {
+        matches_fields!(@fields $variant ; [ $($done)* ($field ; $matcher ; $($mode)*) ] $($rest)*)
+    };
+    (@fields $variant:path ; [ $($done:tt)* ] $field:ident : $matcher:expr $( => $mode:ident )? ) => {
+        matches_fields!(@fields $variant ; [ $($done)* ($field ; $matcher ; $($mode)*) ])
+    };
+    (@on_failure ; $builder:ident ; $failed_msgs:ident ; $path:ident) => {};
+    (@on_failure collect ; $builder:ident ; $failed_msgs:ident ; $path:ident) => {};
+    (@on_failure fatal ; $builder:ident ; $failed_msgs:ident ; $path:ident) => {
+        $failed_msgs.push(format!("remaining fields were not checked as field '{}' is fatal", $path));
+        return $builder.failed_because(&$failed_msgs.join("\n"));
+    };
+    (@on_failure $mode:ident ; $builder:ident ; $failed_msgs:ident ; $path:ident) => {
+        compile_error!(concat!("unknown field mode '", stringify!($mode), "', expected 'fatal' or 'collect'"));
+    };
+    ( $variant:path { $($fields:tt)* } ) => {
+        matches_fields!(@fields $variant ; [] $($fields)*)
+    };
+}
+
 /// Shorter name for `has_structure!`.
+///
+/// Nested `has_structure!`/`structure!` patterns of struct-like fields are expanded with the path of the enclosing fields
+/// as prefix, so their failure messages report the full dotted path of the failing field.
 #[macro_export]
 macro_rules! structure {
-    ( $variant:path { $( $field:ident : $matcher:expr ),* $(,)* } ) => {
+    ( $variant:path { $($fields:tt)* } ) => {
+        structure!(@prefixed [] $variant { $($fields)* })
+    };
+
+    (@nested [$($prefix:ident)*] ( $($pattern:tt)* )) => { structure!(@prefixed [$($prefix)*] $($pattern)*) };
+    (@nested [$($prefix:ident)*] [ $($pattern:tt)* ]) => { structure!(@prefixed [$($prefix)*] $($pattern)*) };
+    (@nested [$($prefix:ident)*] { $($pattern:tt)* }) => { structure!(@prefixed [$($prefix)*] $($pattern)*) };
+
+    (@prefixed [$($prefix:ident)*] $variant:path { $($fields:tt)* } ) => {
+        structure!(@fields [$($prefix)*] $variant ; [] $($fields)*)
+    };
+    (@prefixed [$($prefix:ident)*] $variant:path [ $( $matchers:expr ),* ] ) => {
+        structure![ @expand ( [$($prefix)*] $variant ; x ; $($matchers),* ; ) -> () ]
+    };
+
+    (@fields [$($prefix:ident)*] $variant:path ; [ $( ($field:ident ; $matcher:expr) )* ] ) => {
         Box::new(|actual: &_| {
             use galvanic_assert::{MatchResultBuilder, MatchResult};
             let builder = MatchResultBuilder::for_("has_structure");
+            let prefix = concat!($( stringify!($prefix), ".", )* "");
             #[allow(unreachable_patterns)]
             match actual {
                 &$variant { $( ref $field, )* ..} => {
@@ -410,8 +1418,8 @@ macro_rules! structure {
                     $(
                         if let MatchResult::Failed{ name, reason } = $matcher.check($field) {
                             failed_msgs.push(
-                                format!("Matcher '{}' for field '{}' at {}:{} failed:\n\t{}",
-                                        name, stringify!($field), file!().to_string(), line!(), reason)
+                                format!("Matcher '{}' for field '{}{}' at {}:{} failed:\n\t{}",
+                                        name, prefix, stringify!($field), file!().to_string(), line!(), reason)
                             );
                         }
                     )*
@@ -424,17 +1432,33 @@ macro_rules! structure {
             }
         })
     };
+    (@fields [$($prefix:ident)*] $variant:path ; [ $($done:tt)* ] $field:ident : has_structure! $inner:tt $(, $($rest:tt)*)* ) => {
+        structure!(@fields [$($prefix)*] $variant ; [ $($done)* ] $field : structure! $inner $(, $($rest)*)*)
+    };
+    (@fields [$($prefix:ident)*] $variant:path ; [ $($done:tt)* ] $field:ident : structure! $inner:tt , $($rest:tt)* ) => {
+        structure!(@fields [$($prefix)*] $variant ; [ $($done)* ($field ; structure!(@nested [$($prefix)* $field] $inner)) ] $($rest)*)
+    };
+    (@fields [$($prefix:ident)*] $variant:path ; [ $($done:tt)* ] $field:ident : structure! $inner:tt ) => {
+        structure!(@fields [$($prefix)*] $variant ; [ $($done)* ($field ; structure!(@nested [$($prefix)* $field] $inner)) ])
+    };
+    (@fields [$($prefix:ident)*] $variant:path ; [ $($done:tt)* ] $field:ident : $matcher:expr , $($rest:tt)* ) => {
+        structure!(@fields [$($prefix)*] $variant ; [ $($done)* ($field ; $matcher) ] $($rest)*)
+    };
+    (@fields [$($prefix:ident)*] $variant:path ; [ $($done:tt)* ] $field:ident : $matcher:expr ) => {
+        structure!(@fields [$($prefix)*] $variant ; [ $($done)* ($field ; $matcher) ])
+    };
 
-    (@expand ( $variant:path ; $field:ident ; $m:expr ; $($wildcard:tt),* ) -> ($($body:tt)*) ) => {
-        structure!(@generate ($field ; $($body)* ($m ; &$variant($($wildcard,)* ref $field))) )
+    (@expand ( [$($prefix:ident)*] $variant:path ; $field:ident ; $m:expr ; $($wildcard:tt),* ) -> ($($body:tt)*) ) => {
+        structure!(@generate ([$($prefix)*] $field ; $($body)* ($m ; &$variant($($wildcard,)* ref $field))) )
     };
-    (@expand ( $variant:path ; $field:ident ; $m:expr , $($matchers:expr),* ; $($wildcard:tt),* ) -> ($($body:tt)*) ) => {
-        structure!(@expand ( $variant ; $field ; $($matchers),* ; $($wildcard,)* _ ) -> ($($body)* ($m ; &$variant($($wildcard,)* ref $field, ..)),) )
+    (@expand ( [$($prefix:ident)*] $variant:path ; $field:ident ; $m:expr , $($matchers:expr),* ; $($wildcard:tt),* ) -> ($($body:tt)*) ) => {
+        structure!(@expand ( [$($prefix)*] $variant ; $field ; $($matchers),* ; $($wildcard,)* _ ) -> ($($body)* ($m ; &$variant($($wildcard,)* ref $field, ..)),) )
     };
-    (@generate ($field:ident ; $(($matcher:expr ; $pattern:pat)),*) ) => {
+    (@generate ([$($prefix:ident)*] $field:ident ; $(($matcher:expr ; $pattern:pat)),*) ) => {
         Box::new(|actual: &_| {
             use galvanic_assert::{MatchResultBuilder, MatchResult};
             let builder = MatchResultBuilder::for_("has_structure");
+            let prefix = concat!($( stringify!($prefix), ".", )* "");
 
             let mut failed_msgs = Vec::new();
             $(
@@ -442,8 +1466,8 @@ macro_rules! structure {
                 match actual {
                     $pattern => if let MatchResult::Failed{ name, reason } = $matcher.check($field) {
                         failed_msgs.push(
-                            format!("Matcher '{}' for field '{}' at {}:{} failed:\n\t{}",
-                                    name, stringify!($field), file!().to_string(), line!(), reason)
+                            format!("Matcher '{}' for field '{}{}' at {}:{} failed:\n\t{}",
+                                    name, prefix, stringify!($field), file!().to_string(), line!(), reason)
                         );
                     },
                     _ => return builder.failed_because(
@@ -457,6 +1481,6 @@ macro_rules! structure {
         })
     };
     ( $variant:path [ $( $matchers:expr ),* ] ) => {
-        structure![ @expand ( $variant ; x ; $($matchers),* ; ) -> () ]
+        structure!(@prefixed [] $variant [ $($matchers),* ])
     };
 }
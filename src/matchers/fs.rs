@@ -1,7 +1,8 @@
 use super::super::*;
 
-use std::path::Path;
-use std::fs::File;
+use std::collections::BTreeSet;
+use std::path::{Component, Path, PathBuf};
+use std::fs::{self, File};
 use std::io::Read;
 
 /// Matches the contents (as String) of a file located at the given `Path` against given `Matcher`.
@@ -39,3 +40,179 @@ pub fn content_as_bytes<P: AsRef<Path>>(content_matcher: Box<Matcher<Vec<u8>>>)
         }
     });
 }
+
+fn extension_matches<P: AsRef<Path>>(name: &'static str, expected_extension: &str, ignore_case: bool) -> Box<Matcher<P>> {
+    let expected_extension = expected_extension.trim_start_matches('.').to_owned();
+    return Box::new(move |path: &P| {
+        let builder = MatchResultBuilder::for_(name);
+        match path.as_ref().extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext == expected_extension
+                         || (ignore_case && ext.eq_ignore_ascii_case(&expected_extension)) => builder.matched(),
+            Some(ext) => builder.failed_because(
+                &format!("{:?} has extension '{}' but expected '{}'", path.as_ref(), ext, expected_extension)
+            ),
+            None => builder.failed_because(
+                &format!("{:?} has no extension but expected '{}'", path.as_ref(), expected_extension)
+            )
+        }
+    });
+}
+
+/// Matches if the file located at the given `Path` has the expected extension.
+///
+/// A leading dot in `expected_extension` is ignored, i.e., `"txt"` and `".txt"` are treated the same.
+/// The file does not need to exist.
+pub fn has_extension<P: AsRef<Path>>(expected_extension: &str) -> Box<Matcher<P>> {
+    extension_matches("has_extension", expected_extension, false)
+}
+
+/// Matches if the file located at the given `Path` has the expected extension ignoring ASCII case.
+///
+/// A leading dot in `expected_extension` is ignored, i.e., `"txt"` and `".TXT"` are treated the same.
+/// The file does not need to exist.
+pub fn has_extension_ignoring_case<P: AsRef<Path>>(expected_extension: &str) -> Box<Matcher<P>> {
+    extension_matches("has_extension_ignoring_case", expected_extension, true)
+}
+
+/// Matches if the final component of the given `Path` equals the expected file name.
+///
+/// The directory part of the path is ignored. The file does not need to exist.
+pub fn has_file_name<P: AsRef<Path>>(expected_name: &str) -> Box<Matcher<P>> {
+    let expected_name = expected_name.to_owned();
+    return Box::new(move |path: &P| {
+        let builder = MatchResultBuilder::for_("has_file_name");
+        match path.as_ref().file_name() {
+            Some(name) if name == expected_name.as_str() => builder.matched(),
+            Some(name) => builder.failed_because(
+                &format!("{:?} has file name {:?} but expected {:?}", path.as_ref(), name, expected_name)
+            ),
+            None => builder.failed_because(
+                &format!("{:?} has no file name but expected {:?}", path.as_ref(), expected_name)
+            )
+        }
+    });
+}
+
+/// Lexically normalizes a path by removing `.` components and resolving `..` components.
+///
+/// Symbolic links are not resolved and the file system is not accessed.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {},
+            Component::ParentDir => match normalized.components().next_back() {
+                Some(Component::Normal(_)) => { normalized.pop(); },
+                Some(Component::RootDir) | Some(Component::Prefix(_)) => {},
+                _ => normalized.push(Component::ParentDir.as_os_str())
+            },
+            _ => normalized.push(component.as_os_str())
+        }
+    }
+    normalized
+}
+
+/// Matches if the parent directory of the given `Path` is the `expected` directory.
+///
+/// Both paths are normalized lexically before they are compared. Symbolic links are not resolved
+/// and the file does not need to exist. The parent of a relative path with a single component is the empty path.
+pub fn has_parent<P: AsRef<Path>>(expected: PathBuf) -> Box<Matcher<P>> {
+    let normalized_expected = normalize(&expected);
+    return Box::new(move |path: &P| {
+        let builder = MatchResultBuilder::for_("has_parent");
+        match normalize(path.as_ref()).parent() {
+            Some(parent) if parent == normalized_expected => builder.matched(),
+            Some(parent) => builder.failed_because(
+                &format!("{:?} has parent {:?} but expected {:?}", path.as_ref(), parent, expected)
+            ),
+            None => builder.failed_because(
+                &format!("{:?} has no parent but expected {:?}", path.as_ref(), expected)
+            )
+        }
+    });
+}
+
+/// Counts the leading `..` components of a normalized path.
+fn leading_parent_dirs(path: &Path) -> usize {
+    path.components().take_while(|component| *component == Component::ParentDir).count()
+}
+
+/// Matches if the given `Path` is located inside of the `base` directory.
+///
+/// Both paths are normalized lexically before they are compared, so `..` components
+/// escaping the `base` directory are detected. Symbolic links are not resolved and
+/// the file does not need to exist. The `base` directory itself is considered to be inside of `base`.
+/// A `base` which normalizes to the empty path, e.g., `"."` or `"a/.."`, contains all relative paths without leading `..`.
+pub fn is_inside<P: AsRef<Path>>(base: PathBuf) -> Box<Matcher<P>> {
+    let normalized_base = normalize(&base);
+    return Box::new(move |path: &P| {
+        let builder = MatchResultBuilder::for_("is_inside");
+        let normalized_path = normalize(path.as_ref());
+        if normalized_path.starts_with(&normalized_base)
+           && normalized_path.has_root() == normalized_base.has_root()
+           && leading_parent_dirs(&normalized_path) == leading_parent_dirs(&normalized_base) {
+            builder.matched()
+        } else {
+            builder.failed_because(
+                &format!("{:?} (normalized: {:?}) is not inside of {:?} (normalized: {:?})",
+                         path.as_ref(), normalized_path, base, normalized_base)
+            )
+        }
+    });
+}
+
+/// Collects the paths of all entries below `dir` relative to the root directory of the walk.
+///
+/// Directories at `max_depth` are not descended into. Returns whether any entries were skipped because of `max_depth`.
+fn collect_tree_entries(dir: &Path, relative: &Path, depth: usize, max_depth: Option<usize>,
+                        entries: &mut BTreeSet<PathBuf>) -> std::io::Result<bool> {
+    let mut truncated = false;
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let relative_entry = relative.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            if max_depth.map_or(true, |max_depth| depth < max_depth) {
+                truncated |= collect_tree_entries(&entry.path(), &relative_entry, depth + 1, max_depth, entries)?;
+            } else if fs::read_dir(entry.path())?.next().is_some() {
+                truncated = true;
+            }
+        }
+        entries.insert(relative_entry);
+    }
+    Ok(truncated)
+}
+
+/// Matches if the directory located at the given `Path` contains exactly the expected entries up to `max_depth` levels.
+///
+/// The expected entries are paths of files and directories relative to the asserted directory,
+/// intermediate directories need to be listed as well. Direct children of the directory are on level 1.
+/// Entries below `max_depth` are ignored, if `max_depth` is `None` the whole tree is compared.
+/// On failure the missing and unexpected entries are reported and whether the depth limit truncated the comparison.
+pub fn has_directory_tree<P: AsRef<Path>>(expected_entries: Vec<&str>, max_depth: Option<usize>) -> Box<Matcher<P>> {
+    let expected: BTreeSet<PathBuf> = expected_entries.into_iter().map(PathBuf::from).collect();
+    return Box::new(move |path: &P| {
+        let builder = MatchResultBuilder::for_("has_directory_tree");
+        let mut actual = BTreeSet::new();
+        let walk = match max_depth {
+            Some(0) => fs::read_dir(path.as_ref()).map(|mut entries| entries.next().is_some()),
+            _ => collect_tree_entries(path.as_ref(), Path::new(""), 1, max_depth, &mut actual)
+        };
+        let truncated = match walk {
+            Ok(truncated) => truncated,
+            Err(err) => return builder.failed_because(&format!("cannot read {:?}: {}", path.as_ref(), err))
+        };
+
+        let missing: Vec<_> = expected.difference(&actual).collect();
+        let unexpected: Vec<_> = actual.difference(&expected).collect();
+        if missing.is_empty() && unexpected.is_empty() {
+            return builder.matched();
+        }
+
+        let mut reason = format!("the directory tree of {:?} differs, missing entries: {:?}, unexpected entries: {:?}",
+                                 path.as_ref(), missing, unexpected);
+        if truncated {
+            reason.push_str(&format!("\n\tentries below level {} were not compared", max_depth.unwrap_or(0)));
+        }
+        builder.failed_because(&reason)
+    });
+}
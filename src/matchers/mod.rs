@@ -15,6 +15,9 @@
 
 pub mod collection;
 pub mod fs;
+#[cfg(feature = "serde")]
+pub mod serialization;
+pub mod string;
 pub mod variant;
 
 mod core;
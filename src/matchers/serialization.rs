@@ -0,0 +1,101 @@
+/* Copyright 2017 Christopher Bacher
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! The serialization module contains matchers for asserting properties of values implementing `serde::Serialize`.
+//!
+//! The module is only available if the `serde` feature is enabled.
+
+use super::super::*;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::fmt::Debug;
+use serde_json::Value;
+
+/// Serializes the given value into its canonical JSON form.
+///
+/// The keys of maps and structs are ordered so the representation does not depend on the iteration order.
+fn to_canonical<T: Serialize>(value: &T) -> Result<Value, serde_json::Error> {
+    serde_json::to_value(value)
+}
+
+/// Matches if the serialized forms of the asserted value and the expected value are equal.
+///
+/// Both values are serialized into a canonical JSON representation which is compared instead of the values.
+/// This allows to assert structural equality of types which do not implement `PartialEq`.
+/// If either value fails to serialize the matcher is considered to be failed.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::serialization::*;
+/// # fn main() {
+/// assert_that!(&vec![(1, "one"), (2, "two")], serialized_equal_to(vec![(1, "one"), (2, "two")]));
+/// # }
+pub fn serialized_equal_to<'a, T>(expected: T) -> Box<Matcher<T> + 'a>
+where T: Serialize + 'a {
+    Box::new(move |actual: &T| {
+        let builder = MatchResultBuilder::for_("serialized_equal_to");
+        let actual_value = match to_canonical(actual) {
+            Ok(value) => value,
+            Err(err) => return builder.failed_because(&format!("failed to serialize actual value: {}", err))
+        };
+        let expected_value = match to_canonical(&expected) {
+            Ok(value) => value,
+            Err(err) => return builder.failed_because(&format!("failed to serialize expected value: {}", err))
+        };
+
+        if actual_value == expected_value {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("the serialized values differ\n\tExpected: {}\n\tGot: {}",
+                                            expected_value, actual_value))
+        }
+    })
+}
+
+/// Matches if the asserted value is equal to itself after being serialized to JSON and deserialized again.
+///
+/// On failure the serialized JSON and the deserialized value are reported.
+/// If serialization or deserialization fails the matcher is considered to be failed.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::serialization::*;
+/// # fn main() {
+/// assert_that!(&vec![(1, "one".to_owned()), (2, "two".to_owned())], round_trips_via_json());
+/// # }
+pub fn round_trips_via_json<'a, T>() -> Box<Matcher<T> + 'a>
+where T: Serialize + DeserializeOwned + PartialEq + Debug + 'a {
+    Box::new(|actual: &T| {
+        let builder = MatchResultBuilder::for_("round_trips_via_json");
+        let json = match serde_json::to_string(actual) {
+            Ok(json) => json,
+            Err(err) => return builder.failed_because(&format!("failed to serialize {:?}: {}", actual, err))
+        };
+        let deserialized: T = match serde_json::from_str(&json) {
+            Ok(value) => value,
+            Err(err) => return builder.failed_because(&format!("failed to deserialize {}: {}", json, err))
+        };
+
+        if &deserialized == actual {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("the value changed during the round trip via {}\n\tExpected: {:?}\n\tGot: {:?}",
+                                            json, actual, deserialized))
+        }
+    })
+}
@@ -0,0 +1,658 @@
+/* Copyright 2017 Christopher Bacher
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! The string module contains matchers for asserting properties of strings.
+//!
+//! The matchers in this module accept any string-like value implementing `AsRef<str>`,
+//! e.g., `String`, `&str`, `Box<str>`, or `Cow<str>`.
+//! Matchers asserting a rendered form, e.g., `displays_with_length` or `error_displays`,
+//! accept any value implementing `Display` or `Error` respectively.
+//! Matchers for byte strings, e.g., `is_printable_ascii`, accept any value implementing `AsRef<[u8]>`.
+
+use super::super::*;
+use std::error::Error;
+use std::fmt::Display;
+
+/// Matches a string-like value against a `Matcher` for `String`s.
+///
+/// The asserted value is converted to a `String` before it is passed to the nested `Matcher`.
+/// This allows to use `String` matchers like `eq` for values of type `Cow<str>`, `Box<str>`, or `&str`.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// use galvanic_assert::matchers::string::*;
+/// use std::borrow::Cow;
+/// # fn main() {
+/// let cow: Cow<str> = Cow::Borrowed("foo");
+/// assert_that!(&cow, as_str(eq("foo".to_owned())));
+/// # }
+pub fn as_str<'a, S>(matcher: Box<Matcher<String> + 'a>) -> Box<Matcher<S> + 'a>
+where S: AsRef<str> + 'a {
+    Box::new(move |actual: &S| matcher.check(&actual.as_ref().to_owned()))
+}
+
+/// Matches if the asserted string equals one of the `candidates` ignoring case.
+///
+/// Case is compared after converting both sides to lowercase according to Unicode.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::string::*;
+/// # fn main() {
+/// let candidates = vec!["true".to_owned(), "yes".to_owned(), "on".to_owned()];
+/// assert_that!(&"Yes", one_of_ignoring_case(candidates));
+/// # }
+pub fn one_of_ignoring_case<'a, S>(candidates: Vec<String>) -> Box<Matcher<S> + 'a>
+where S: AsRef<str> + 'a {
+    let lowercase_candidates: Vec<_> = candidates.iter().map(|c| c.to_lowercase()).collect();
+    Box::new(move |actual: &S| {
+        let builder = MatchResultBuilder::for_("one_of_ignoring_case");
+        let lowercase_actual = actual.as_ref().to_lowercase();
+        if lowercase_candidates.contains(&lowercase_actual) {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} is none of {:?} (ignoring case)", actual.as_ref(), candidates))
+        }
+    })
+}
+
+/// Matches if the asserted string consists of the expected number of words.
+///
+/// Words are separated by any amount of whitespace, see `str::split_whitespace`.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::string::*;
+/// # fn main() {
+/// assert_that!(&"the quick  brown\nfox", has_word_count(4));
+/// # }
+pub fn has_word_count<'a, S>(expected: usize) -> Box<Matcher<S> + 'a>
+where S: AsRef<str> + 'a {
+    Box::new(move |actual: &S| {
+        let builder = MatchResultBuilder::for_("has_word_count");
+        let count = actual.as_ref().split_whitespace().count();
+        if count == expected {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} has {} words but expected {}", actual.as_ref(), count, expected))
+        }
+    })
+}
+
+/// Matches if the asserted string consists of the expected number of lines.
+///
+/// Lines are separated by `\n` or `\r\n`, a trailing line ending does not start a new line, see `str::lines`.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::string::*;
+/// # fn main() {
+/// assert_that!(&"first\nsecond\r\nthird\n", has_line_count(3));
+/// # }
+pub fn has_line_count<'a, S>(expected: usize) -> Box<Matcher<S> + 'a>
+where S: AsRef<str> + 'a {
+    Box::new(move |actual: &S| {
+        let builder = MatchResultBuilder::for_("has_line_count");
+        let count = actual.as_ref().lines().count();
+        if count == expected {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} has {} lines but expected {}", actual.as_ref(), count, expected))
+        }
+    })
+}
+
+/// Matches if every line of the asserted string starts with the given `prefix`.
+///
+/// Lines are split like `str::lines`, so a trailing line ending does not produce an additional empty line.
+/// On failure the first offending line and its 1-based line number are reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::string::*;
+/// # fn main() {
+/// assert_that!(&"> quoted\n> reply\n", every_line_starts_with("> "));
+/// # }
+pub fn every_line_starts_with<'a, S>(prefix: &str) -> Box<Matcher<S> + 'a>
+where S: AsRef<str> + 'a {
+    let prefix = prefix.to_owned();
+    Box::new(move |actual: &S| {
+        let builder = MatchResultBuilder::for_("every_line_starts_with");
+        match actual.as_ref().lines().enumerate().find(|&(_, line)| !line.starts_with(prefix.as_str())) {
+            None => builder.matched(),
+            Some((idx, line)) => builder.failed_because(
+                &format!("line {} {:?} does not start with {:?}", idx + 1, line, prefix)
+            )
+        }
+    })
+}
+
+/// Matches if the asserted string has neither leading nor trailing whitespace.
+///
+/// On failure the offending side and whitespace characters are reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::string::*;
+/// # fn main() {
+/// assert_that!(&"foo bar", is_trimmed());
+/// assert_that!(
+///     assert_that!(&" foo bar", is_trimmed()),
+///     panics
+/// );
+/// # }
+pub fn is_trimmed<'a, S>() -> Box<Matcher<S> + 'a>
+where S: AsRef<str> + 'a {
+    Box::new(|actual: &S| {
+        let builder = MatchResultBuilder::for_("is_trimmed");
+        let actual = actual.as_ref();
+        let trimmed_start = actual.trim_start();
+        let leading = &actual[..actual.len() - trimmed_start.len()];
+        let trailing = &trimmed_start[trimmed_start.trim_end().len()..];
+
+        let mut failed_msgs = Vec::new();
+        if !leading.is_empty() {
+            failed_msgs.push(format!("{:?} has leading whitespace: {:?}", actual, leading));
+        }
+        if !trailing.is_empty() {
+            failed_msgs.push(format!("{:?} has trailing whitespace: {:?}", actual, trailing));
+        }
+
+        if failed_msgs.is_empty() { builder.matched() }
+        else { builder.failed_because(&failed_msgs.join("\n\t")) }
+    })
+}
+
+/// Matches the number of characters of the asserted value's `Display` representation against the passed `Matcher`.
+///
+/// The value is rendered with `{}` and its length is counted in Unicode scalar values, not bytes.
+/// On failure the rendered string and its length are reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// use galvanic_assert::matchers::string::*;
+/// # fn main() {
+/// assert_that!(&12345, displays_with_length(eq(5)));
+/// assert_that!(&"größe", displays_with_length(leq(8)));
+/// # }
+pub fn displays_with_length<'a, T>(matcher: Box<Matcher<usize> + 'a>) -> Box<Matcher<T> + 'a>
+where T: Display + 'a {
+    Box::new(move |actual: &T| {
+        let rendered = format!("{}", actual);
+        let length = rendered.chars().count();
+        match matcher.check(&length) {
+            x@MatchResult::Matched { .. } => x,
+            MatchResult::Failed { name, reason } =>
+                MatchResultBuilder::for_(&format!("displays_with_length({})", name))
+                                   .failed_because(&format!("{:?} is displayed with length {}: {}", rendered, length, reason))
+        }
+    })
+}
+
+/// Matches if the asserted string contains all of the given substrings.
+///
+/// The substrings may appear in any order and may overlap.
+/// On failure all missing substrings are reported at once.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::string::*;
+/// # fn main() {
+/// assert_that!(&"user=foo id=42 status=ok", contains_all_substrings(vec!["status=ok", "user=foo"]));
+/// # }
+pub fn contains_all_substrings<'a, S>(needles: Vec<&str>) -> Box<Matcher<S> + 'a>
+where S: AsRef<str> + 'a {
+    let needles: Vec<String> = needles.into_iter().map(|n| n.to_owned()).collect();
+    Box::new(move |actual: &S| {
+        let builder = MatchResultBuilder::for_("contains_all_substrings");
+        let actual = actual.as_ref();
+        let missing: Vec<_> = needles.iter().filter(|needle| !actual.contains(needle.as_str())).collect();
+        if missing.is_empty() {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} is missing the substrings: {:?}", actual, missing))
+        }
+    })
+}
+
+/// Matches if the asserted string contains the given substrings in the given order.
+///
+/// Each substring is searched for after the end of the previous substring's match, so matches do not overlap.
+/// Arbitrary text may appear between the substrings.
+/// On failure the substring which could not be found after the previous one is reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::string::*;
+/// # fn main() {
+/// let log = "START tx=1\nwrite a\nCOMMIT\nEND";
+/// assert_that!(&log, contains_substrings_in_order(vec!["START", "COMMIT", "END"]));
+/// assert_that!(
+///     assert_that!(&log, contains_substrings_in_order(vec!["COMMIT", "START"])),
+///     panics
+/// );
+/// # }
+pub fn contains_substrings_in_order<'a, S>(needles: Vec<&str>) -> Box<Matcher<S> + 'a>
+where S: AsRef<str> + 'a {
+    let needles: Vec<String> = needles.into_iter().map(|n| n.to_owned()).collect();
+    Box::new(move |actual: &S| {
+        let builder = MatchResultBuilder::for_("contains_substrings_in_order");
+        let actual = actual.as_ref();
+        let mut position = 0;
+        for (idx, needle) in needles.iter().enumerate() {
+            match actual[position..].find(needle.as_str()) {
+                Some(offset) => position += offset + needle.len(),
+                None if idx == 0 => return builder.failed_because(
+                    &format!("{:?} does not contain {:?}", actual, needle)
+                ),
+                None => return builder.failed_because(
+                    &format!("{:?} does not contain {:?} after {:?} (ending at byte {})",
+                             actual, needle, needles[idx - 1], position)
+                )
+            }
+        }
+        builder.matched()
+    })
+}
+
+/// Matches if the asserted string split by `delimiter` yields fields matching the given `matchers`.
+///
+/// The i-th field is checked by the i-th `Matcher`, so the number of fields must equal the number of matchers.
+/// On failure either the field count mismatch or the index and reason of every failed field are reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// use galvanic_assert::matchers::string::*;
+/// # fn main() {
+/// assert_that!(&"42;alice;admin", split_by(";", vec![
+///     looks_numeric(),
+///     eq("alice".to_owned()),
+///     any_value()
+/// ]));
+/// # }
+pub fn split_by<'a, S>(delimiter: &str, matchers: Vec<Box<Matcher<String> + 'a>>) -> Box<Matcher<S> + 'a>
+where S: AsRef<str> + 'a {
+    let delimiter = delimiter.to_owned();
+    Box::new(move |actual: &S| {
+        let builder = MatchResultBuilder::for_("split_by");
+        let fields: Vec<String> = actual.as_ref().split(delimiter.as_str()).map(|f| f.to_owned()).collect();
+        if fields.len() != matchers.len() {
+            return builder.failed_because(
+                &format!("{:?} split by {:?} has {} fields but expected {}: {:?}",
+                         actual.as_ref(), delimiter, fields.len(), matchers.len(), fields)
+            );
+        }
+
+        let failed_msgs: Vec<_> = fields.iter().zip(matchers.iter()).enumerate().filter_map(|(idx, (field, matcher))| {
+            match matcher.check(field) {
+                MatchResult::Matched { .. } => None,
+                MatchResult::Failed { name, reason } => Some(
+                    format!("field {} {:?} failed to match {}: {}", idx, field, name, reason)
+                )
+            }
+        }).collect();
+
+        if failed_msgs.is_empty() { builder.matched() }
+        else { builder.failed_because(&failed_msgs.join("\n")) }
+    })
+}
+
+/// Matches the `Display` message of the asserted error against the passed `Matcher`.
+///
+/// The error is rendered with `{}`, i.e., the user-facing message is checked and not the `Debug` representation.
+/// On failure the full rendered message is reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// use galvanic_assert::matchers::string::*;
+/// # fn main() {
+/// let error = "x".parse::<i32>().unwrap_err();
+/// assert_that!(&error, error_displays(eq("invalid digit found in string".to_owned())));
+/// # }
+pub fn error_displays<'a, T>(matcher: Box<Matcher<String> + 'a>) -> Box<Matcher<T> + 'a>
+where T: Error + 'a {
+    Box::new(move |actual: &T| {
+        let message = format!("{}", actual);
+        match matcher.check(&message) {
+            x@MatchResult::Matched { .. } => x,
+            MatchResult::Failed { name, reason } =>
+                MatchResultBuilder::for_(&format!("error_displays({})", name))
+                                   .failed_because(&format!("error is displayed as {:?}: {}", message, reason))
+        }
+    })
+}
+
+/// Scans a numeric literal and returns the byte position and description of the first error.
+fn scan_numeric(literal: &str) -> Result<(), (usize, String)> {
+    let bytes = literal.as_bytes();
+    let mut pos = 0;
+    let skip_digits = |mut pos: usize| { while pos < bytes.len() && bytes[pos].is_ascii_digit() { pos += 1; } pos };
+    let unexpected = |pos: usize, expected: &str| match literal[pos..].chars().next() {
+        Some(c) => Err((pos, format!("unexpected character {:?}, expected {}", c, expected))),
+        None => Err((pos, format!("unexpected end of input, expected {}", expected)))
+    };
+
+    if pos < bytes.len() && (bytes[pos] == b'+' || bytes[pos] == b'-') { pos += 1; }
+    let integral_start = pos;
+    pos = skip_digits(pos);
+    let mut has_digits = pos > integral_start;
+    if pos < bytes.len() && bytes[pos] == b'.' {
+        let fraction_start = pos + 1;
+        pos = skip_digits(fraction_start);
+        has_digits = has_digits || pos > fraction_start;
+    }
+    if !has_digits {
+        return unexpected(pos, "a digit");
+    }
+    if pos < bytes.len() && (bytes[pos] == b'e' || bytes[pos] == b'E') {
+        pos += 1;
+        if pos < bytes.len() && (bytes[pos] == b'+' || bytes[pos] == b'-') { pos += 1; }
+        let exponent_start = pos;
+        pos = skip_digits(pos);
+        if pos == exponent_start {
+            return unexpected(pos, "a digit in the exponent");
+        }
+    }
+    if pos < bytes.len() {
+        return unexpected(pos, "the end of the number");
+    }
+    Ok(())
+}
+
+/// Matches if the asserted string looks like a numeric literal without parsing it to a specific type.
+///
+/// A numeric literal consists of an optional sign, digits with an optional decimal point,
+/// and an optional exponent, e.g., `42`, `-3.14`, `.5`, or `1e-3`.
+/// At least one digit is required before the exponent. Surrounding whitespace is not allowed.
+/// On failure the first invalid character and its byte position are reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::string::*;
+/// # fn main() {
+/// assert_that!(&"-12.5e3", looks_numeric());
+/// assert_that!(
+///     assert_that!(&"12,5", looks_numeric()),
+///     panics
+/// );
+/// # }
+pub fn looks_numeric<'a, S>() -> Box<Matcher<S> + 'a>
+where S: AsRef<str> + 'a {
+    Box::new(|actual: &S| {
+        let builder = MatchResultBuilder::for_("looks_numeric");
+        match scan_numeric(actual.as_ref()) {
+            Ok(()) => builder.matched(),
+            Err((pos, reason)) => builder.failed_because(
+                &format!("{:?} is not numeric at byte {}: {}", actual.as_ref(), pos, reason)
+            )
+        }
+    })
+}
+
+/// Matches if the asserted string is a valid identifier.
+///
+/// An identifier starts with an ASCII letter or `'_'` followed by any number of ASCII letters, digits, or `'_'`.
+/// On failure the first invalid character and its character position are reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::string::*;
+/// # fn main() {
+/// assert_that!(&"_user_id2", is_valid_identifier());
+/// assert_that!(
+///     assert_that!(&"user-id", is_valid_identifier()),
+///     panics
+/// );
+/// # }
+pub fn is_valid_identifier<'a, S>() -> Box<Matcher<S> + 'a>
+where S: AsRef<str> + 'a {
+    Box::new(|actual: &S| {
+        let builder = MatchResultBuilder::for_("is_valid_identifier");
+        let offending = actual.as_ref().chars().enumerate().find(|&(pos, c)| match c {
+            'a' ..= 'z' | 'A' ..= 'Z' | '_' => false,
+            '0' ..= '9' => pos == 0,
+            _ => true
+        });
+        match offending {
+            None if actual.as_ref().is_empty() => builder.failed_because("the empty string is not an identifier"),
+            None => builder.matched(),
+            Some((pos, c)) => builder.failed_because(
+                &format!("{:?} has invalid character {:?} at position {}", actual.as_ref(), c, pos)
+            )
+        }
+    })
+}
+
+/// Matches if the asserted byte string consists only of printable ASCII characters and common whitespace.
+///
+/// Printable characters are in the range from `' '` to `'~'`, allowed whitespace are `'\t'`, `'\n'`, and `'\r'`.
+/// On failure the first offending byte and its offset are reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::string::*;
+/// # fn main() {
+/// assert_that!(&b"log line\n".to_vec(), is_printable_ascii());
+/// assert_that!(
+///     assert_that!(&vec![b'o', b'k', 0x07], is_printable_ascii()),
+///     panics
+/// );
+/// # }
+pub fn is_printable_ascii<'a, B>() -> Box<Matcher<B> + 'a>
+where B: AsRef<[u8]> + 'a {
+    Box::new(|actual: &B| {
+        let builder = MatchResultBuilder::for_("is_printable_ascii");
+        let offending = actual.as_ref().iter().enumerate().find(|&(_, &byte)| match byte {
+            b' ' ..= b'~' | b'\t' | b'\n' | b'\r' => false,
+            _ => true
+        });
+        match offending {
+            None => builder.matched(),
+            Some((offset, byte)) => builder.failed_because(
+                &format!("byte {:#04x} at offset {} is not printable ASCII", byte, offset)
+            )
+        }
+    })
+}
+
+/// Matches if the asserted string has the expected length in bytes of its UTF-8 encoding.
+///
+/// On failure both the byte length and the character length are reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::string::*;
+/// # fn main() {
+/// assert_that!(&"größe", has_byte_length(7));
+/// # }
+pub fn has_byte_length<'a, S>(expected: usize) -> Box<Matcher<S> + 'a>
+where S: AsRef<str> + 'a {
+    Box::new(move |actual: &S| {
+        let builder = MatchResultBuilder::for_("has_byte_length");
+        let actual = actual.as_ref();
+        if actual.len() == expected {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} has {} bytes ({} chars) but expected {} bytes",
+                                            actual, actual.len(), actual.chars().count(), expected))
+        }
+    })
+}
+
+/// Matches if the asserted string has the expected number of characters, i.e., Unicode scalar values.
+///
+/// On failure both the character length and the byte length are reported.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::string::*;
+/// # fn main() {
+/// assert_that!(&"größe", has_char_length(5));
+/// # }
+pub fn has_char_length<'a, S>(expected: usize) -> Box<Matcher<S> + 'a>
+where S: AsRef<str> + 'a {
+    Box::new(move |actual: &S| {
+        let builder = MatchResultBuilder::for_("has_char_length");
+        let actual = actual.as_ref();
+        let char_length = actual.chars().count();
+        if char_length == expected {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} has {} chars ({} bytes) but expected {} chars",
+                                            actual, char_length, actual.len(), expected))
+        }
+    })
+}
+
+/// A single element of a parsed glob pattern.
+#[derive(Debug)]
+enum GlobToken {
+    Literal(char),
+    AnyChar,
+    AnySequence,
+    /// A character class given by its ranges and whether it is negated.
+    Class(Vec<(char, char)>, bool)
+}
+
+/// Parses a shell-style glob pattern supporting `*`, `?`, and `[...]`.
+fn parse_glob(pattern: &str) -> Result<Vec<GlobToken>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        tokens.push(match c {
+            '*' => GlobToken::AnySequence,
+            '?' => GlobToken::AnyChar,
+            '[' => {
+                let negated = match chars.peek() {
+                    Some(&'!') | Some(&'^') => { chars.next(); true },
+                    _ => false
+                };
+                let mut ranges = Vec::new();
+                let mut first = true;
+                loop {
+                    let start = match chars.next() {
+                        Some(']') if !first => break,
+                        Some(start) => start,
+                        None => return Err(format!("unclosed character class in glob pattern {:?}", pattern))
+                    };
+                    first = false;
+                    let is_range = {
+                        let mut lookahead = chars.clone();
+                        lookahead.next() == Some('-') && lookahead.next().map_or(false, |end| end != ']')
+                    };
+                    if is_range {
+                        chars.next();
+                        let end = chars.next().unwrap();
+                        ranges.push((start, end));
+                    } else {
+                        ranges.push((start, start));
+                    }
+                }
+                GlobToken::Class(ranges, negated)
+            },
+            c => GlobToken::Literal(c)
+        });
+    }
+    Ok(tokens)
+}
+
+/// Returns whether the `text` matches the parsed glob pattern.
+fn glob_matches(tokens: &[GlobToken], text: &[char]) -> bool {
+    let (mut t, mut p) = (0, 0);
+    // position after the last `*` in the pattern and the text position it is currently matched up to
+    let mut backtrack: Option<(usize, usize)> = None;
+    while t < text.len() {
+        let advance = match tokens.get(p) {
+            Some(&GlobToken::AnySequence) => {
+                backtrack = Some((p + 1, t));
+                p += 1;
+                continue;
+            },
+            Some(&GlobToken::AnyChar) => true,
+            Some(&GlobToken::Literal(c)) => c == text[t],
+            Some(&GlobToken::Class(ref ranges, negated)) =>
+                ranges.iter().any(|&(start, end)| start <= text[t] && text[t] <= end) != negated,
+            None => false
+        };
+        if advance {
+            p += 1;
+            t += 1;
+        } else if let Some((star_p, star_t)) = backtrack {
+            // let the last `*` consume one more character
+            backtrack = Some((star_p, star_t + 1));
+            p = star_p;
+            t = star_t + 1;
+        } else {
+            return false;
+        }
+    }
+    tokens[p..].iter().all(|token| match *token { GlobToken::AnySequence => true, _ => false })
+}
+
+/// Matches if the asserted string matches the shell-style glob `pattern`.
+///
+/// The pattern supports `*` for any sequence of characters, `?` for a single character,
+/// and character classes like `[abc]`, `[a-z]`, or negated ones like `[!0-9]`.
+/// The whole string has to match the pattern. An invalid pattern never matches.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::string::*;
+/// # fn main() {
+/// assert_that!(&"report-2017.csv", matches_glob("report-[0-9][0-9][0-9][0-9].*"));
+/// assert_that!(
+///     assert_that!(&"report.txt", matches_glob("*.csv")),
+///     panics
+/// );
+/// # }
+pub fn matches_glob<'a, S>(pattern: &str) -> Box<Matcher<S> + 'a>
+where S: AsRef<str> + 'a {
+    let pattern = pattern.to_owned();
+    let tokens = parse_glob(&pattern);
+    Box::new(move |actual: &S| {
+        let builder = MatchResultBuilder::for_("matches_glob");
+        let tokens = match tokens {
+            Ok(ref tokens) => tokens,
+            Err(ref reason) => return builder.failed_because(reason)
+        };
+        let text: Vec<char> = actual.as_ref().chars().collect();
+        if glob_matches(tokens, &text) {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} does not match the glob pattern {:?}", actual.as_ref(), pattern))
+        }
+    })
+}
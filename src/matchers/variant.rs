@@ -16,6 +16,9 @@
 //! The variant module contains matchers for asserting properties of enums and convienience functions for Option and Result.
 
 use super::super::*;
+use std::fmt::Debug;
+use std::mem;
+use std::collections::HashSet;
 
 /// Matches if the asserted value's variant matches the expected variant.
 ///
@@ -54,6 +57,150 @@ macro_rules! is_variant {
     }
 }
 
+/// Matches if the asserted value's variant matches the expected variant and reports the actual value otherwise.
+///
+/// This works like `is_variant!` but requires the enum to implement `Debug`.
+/// On failure the asserted value is included in the failure message.
+///
+/// # Examples
+/// ```
+/// # #[macro_use] extern crate galvanic_assert;
+/// # fn main() {
+/// #[derive(Debug)]
+/// enum MyEnum { Foo, Bar(i32), Baz{x: i32} }
+/// assert_that!(&MyEnum::Bar(2), is_variant_dbg!(MyEnum::Bar));
+/// assert_that!(
+///     assert_that!(&MyEnum::Bar(2), is_variant_dbg!(MyEnum::Baz)),
+///     panics
+/// );
+/// # }
+/// ```
+#[macro_export]
+macro_rules! is_variant_dbg {
+    ( $variant: path ) => {
+        Box::new(|actual: &_| {
+            use galvanic_assert::MatchResultBuilder;
+            let builder = MatchResultBuilder::for_("is_variant_dbg");
+            match actual {
+                &$variant {..} => builder.matched(),
+                _ => builder.failed_because(
+                        &format!("passed variant {:?} does not match '{}'", actual, stringify!($variant))
+                )
+            }
+        })
+    }
+}
+
+/// Matches if the asserted `Result` is an `Err` whose error matches the expected variant.
+///
+/// The error type needs to implement `Debug`.
+/// On failure the message states whether the `Result` was `Ok` or which other error variant it contained.
+///
+/// # Examples
+/// ```
+/// # #[macro_use] extern crate galvanic_assert;
+/// # fn main() {
+/// #[derive(Debug)]
+/// enum MyError { NotFound, Invalid(String) }
+/// let result: Result<i32, MyError> = Err(MyError::NotFound);
+/// assert_that!(&result, err_is_variant!(MyError::NotFound));
+/// assert_that!(
+///     assert_that!(&result, err_is_variant!(MyError::Invalid)),
+///     panics
+/// );
+/// # }
+/// ```
+#[macro_export]
+macro_rules! err_is_variant {
+    ( $variant: path ) => {
+        Box::new(|actual: &Result<_, _>| {
+            use galvanic_assert::MatchResultBuilder;
+            let builder = MatchResultBuilder::for_("err_is_variant");
+            match actual {
+                &Err($variant {..}) => builder.matched(),
+                &Err(ref err) => builder.failed_because(
+                        &format!("passed error {:?} does not match '{}'", err, stringify!($variant))
+                ),
+                &Ok(_) => builder.failed_because(
+                        &format!("passed Result is Ok but expected an error matching '{}'", stringify!($variant))
+                )
+            }
+        })
+    }
+}
+
+/// Matches if the asserted value has the same variant as one of the given sample values.
+///
+/// Only the discriminants are compared using `std::mem::discriminant`,
+/// so the data carried by the variants is ignored.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::variant::*;
+/// # fn main() {
+/// #[derive(Debug)]
+/// enum MyEnum { Foo, Bar(i32), Baz{x: i32} }
+/// assert_that!(&MyEnum::Bar(2), variant_in(&[MyEnum::Foo, MyEnum::Bar(0)]));
+/// assert_that!(
+///     assert_that!(&MyEnum::Baz{x: 1}, variant_in(&[MyEnum::Foo, MyEnum::Bar(0)])),
+///     panics
+/// );
+/// # }
+pub fn variant_in<'a, T:'a + Debug>(samples: &[T]) -> Box<Matcher<T> + 'a> {
+    let discriminants = samples.iter().map(mem::discriminant).collect();
+    matching_discriminants("variant_in", discriminants, format!("{:?}", samples))
+}
+
+/// Matches if the discriminant of the asserted value is contained in `discriminants`.
+///
+/// This is the shared implementation of `variant_in` and `variants!`.
+/// The `accepted` variants are only used for reporting failures.
+#[doc(hidden)]
+pub fn matching_discriminants<'a, T:'a + Debug>(name: &'a str, discriminants: HashSet<mem::Discriminant<T>>, accepted: String)
+                                                -> Box<Matcher<T> + 'a> {
+    Box::new(move |actual: &T| {
+        let builder = MatchResultBuilder::for_(name);
+        if discriminants.contains(&mem::discriminant(actual)) {
+            builder.matched()
+        } else {
+            builder.failed_because(
+                &format!("passed variant {:?} does not match any variant of {}", actual, accepted)
+            )
+        }
+    })
+}
+
+/// Matches if the asserted value has the same variant as one of the given sample values.
+///
+/// This works like `variant_in` but takes the samples as macro arguments, so they do not need to be collected first.
+/// The enum needs to implement `Debug`.
+/// On failure the accepted samples are listed as written and the asserted value is reported.
+///
+/// # Examples
+/// ```
+/// # #[macro_use] extern crate galvanic_assert;
+/// # fn main() {
+/// #[derive(Debug)]
+/// enum MyEnum { Foo, Bar(i32), Baz{x: i32} }
+/// assert_that!(&MyEnum::Bar(2), variants!(MyEnum::Foo, MyEnum::Bar(0)));
+/// assert_that!(
+///     assert_that!(&MyEnum::Baz{x: 1}, variants!(MyEnum::Foo, MyEnum::Bar(0))),
+///     panics
+/// );
+/// # }
+/// ```
+#[macro_export]
+macro_rules! variants {
+    ( $($sample: expr),+ ) => {
+        galvanic_assert::matchers::variant::matching_discriminants(
+            "variants",
+            vec![$(::std::mem::discriminant(&$sample)),+].into_iter().collect(),
+            format!("'{}'", vec![$(stringify!($sample)),+].join(", "))
+        )
+    }
+}
+
 /// Matches the contents of an `Option` againts a passed `Matcher`.
 ///
 /// #Examples
@@ -64,16 +211,70 @@ macro_rules! is_variant {
 /// # fn main() {
 /// assert_that!(&Some(32), maybe_some(eq(32)));
 /// # }
-pub fn maybe_some<'a, T:'a>(matcher: Box<Matcher<T> + 'a>) -> Box<Matcher<Option<T>> + 'a> {
+pub fn maybe_some<'a, T:'a + Debug>(matcher: Box<Matcher<T> + 'a>) -> Box<Matcher<Option<T>> + 'a> {
     Box::new(move |maybe_actual: &Option<T>| {
         maybe_actual.as_ref()
-                    .map_or(MatchResultBuilder::for_("maybe_some")
-                                               .failed_because("passed Option is None; cannot evaluate nested matcher"),
-                            |actual| matcher.check(actual)
+                    .map_or_else(|| MatchResultBuilder::for_("maybe_some")
+                                                        .failed_because(&format!("passed Option is {:?}; cannot evaluate nested matcher", maybe_actual)),
+                                 |actual| matcher.check(actual)
         )
     })
 }
 
+/// Matches if the asserted `Option` is `Some` and its contents are contained in the `candidates`.
+///
+/// The failure message distinguishes whether the `Option` was `None` or the contained value is not a candidate.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::variant::*;
+/// # fn main() {
+/// assert_that!(&Some("red"), some_contained_in(vec!["red", "green", "blue"]));
+/// # }
+pub fn some_contained_in<'a, T:'a + PartialEq + Debug>(candidates: Vec<T>) -> Box<Matcher<Option<T>> + 'a> {
+    Box::new(move |maybe_actual: &Option<T>| {
+        let builder = MatchResultBuilder::for_("some_contained_in");
+        match *maybe_actual {
+            Some(ref actual) if candidates.contains(actual) => builder.matched(),
+            Some(ref actual) => builder.failed_because(
+                &format!("passed Option contains {:?} which is not one of {:?}", actual, candidates)
+            ),
+            None => builder.failed_because(
+                &format!("passed Option is None but expected one of {:?}", candidates)
+            )
+        }
+    })
+}
+
+/// Matches the contents of a nested `Option<Option<T>>` againts a passed `Matcher`.
+///
+/// Both options need to be `Some` for the nested matcher to be evaluated.
+/// On failure the message states whether the outer or the inner `Option` was `None`.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// use galvanic_assert::matchers::variant::*;
+/// # fn main() {
+/// assert_that!(&Some(Some(32)), flattened_some(eq(32)));
+/// assert_that!(
+///     assert_that!(&Some(None), flattened_some(eq(32))),
+///     panics
+/// );
+/// # }
+pub fn flattened_some<'a, T:'a>(matcher: Box<Matcher<T> + 'a>) -> Box<Matcher<Option<Option<T>>> + 'a> {
+    Box::new(move |maybe_actual: &Option<Option<T>>| {
+        let builder = MatchResultBuilder::for_("flattened_some");
+        match maybe_actual.as_ref() {
+            Some(&Some(ref actual)) => matcher.check(actual),
+            Some(&None) => builder.failed_because("inner Option (level 2) is None; cannot evaluate nested matcher"),
+            None => builder.failed_because("outer Option (level 1) is None; cannot evaluate nested matcher")
+        }
+    })
+}
+
 /// Matches the contents of a `Result` if it is `Ok` againts a passed `Matcher`.
 ///
 /// #Examples
@@ -85,16 +286,34 @@ pub fn maybe_some<'a, T:'a>(matcher: Box<Matcher<T> + 'a>) -> Box<Matcher<Option
 /// let ok: Result<i32,()> = Ok(32);
 /// assert_that!(&ok, maybe_ok(eq(32)));
 /// # }
-pub fn maybe_ok<'a, T:'a, E:'a>(matcher: Box<Matcher<T> + 'a>) -> Box<Matcher<Result<T,E>> + 'a> {
+pub fn maybe_ok<'a, T:'a + Debug, E:'a + Debug>(matcher: Box<Matcher<T> + 'a>) -> Box<Matcher<Result<T,E>> + 'a> {
     Box::new(move |maybe_actual: &Result<T,E>| {
         match maybe_actual.as_ref() {
             Ok(actual) => matcher.check(actual),
             Err(_) => MatchResultBuilder::for_("maybe_ok")
-                                       .failed_because("passed Result is Err; cannot evaluate nested matcher")
+                                       .failed_because(&format!("passed Result is {:?}; cannot evaluate nested matcher", maybe_actual))
         }
     })
 }
 
+/// Matches the contents of a `Result` if it is `Ok` againts a passed `Matcher`.
+///
+/// If the `Result` is `Err` the error is included in the failure message.
+/// This is the same as [maybe_ok].
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// use galvanic_assert::matchers::variant::*;
+/// # fn main() {
+/// let ok: Result<i32,()> = Ok(32);
+/// assert_that!(&ok, ok_matching(eq(32)));
+/// # }
+pub fn ok_matching<'a, T:'a + Debug, E:'a + Debug>(matcher: Box<Matcher<T> + 'a>) -> Box<Matcher<Result<T,E>> + 'a> {
+    maybe_ok(matcher)
+}
+
 /// Matches the contents of a `Result` if it is `Err` againts a passed `Matcher`.
 ///
 /// #Examples
@@ -106,12 +325,72 @@ pub fn maybe_ok<'a, T:'a, E:'a>(matcher: Box<Matcher<T> + 'a>) -> Box<Matcher<Re
 /// let err: Result<i32,i32> = Err(32);
 /// assert_that!(&err, maybe_err(eq(32)));
 /// # }
-pub fn maybe_err<'a, T:'a, E:'a>(matcher: Box<Matcher<E> + 'a>) -> Box<Matcher<Result<T,E>> + 'a> {
+pub fn maybe_err<'a, T:'a + Debug, E:'a + Debug>(matcher: Box<Matcher<E> + 'a>) -> Box<Matcher<Result<T,E>> + 'a> {
     Box::new(move |maybe_actual: &Result<T,E>| {
         match maybe_actual.as_ref() {
             Err(actual) => matcher.check(actual),
             Ok(_) => MatchResultBuilder::for_("maybe_err")
-                                        .failed_because("passed Result is Ok; cannot evaluate nested matcher")
+                                        .failed_because(&format!("passed Result is {:?}; cannot evaluate nested matcher", maybe_actual))
+        }
+    })
+}
+
+/// Matches the contents of a `Result<Option<T>, E>` if it is `Ok(Some(_))` againts a passed `Matcher`.
+///
+/// On failure the message states which layer has the wrong variant, i.e., whether the `Result` was `Err`
+/// (including the error) or the `Option` was `None`.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::*;
+/// use galvanic_assert::matchers::variant::*;
+/// # fn main() {
+/// let lookup: Result<Option<i32>,String> = Ok(Some(32));
+/// assert_that!(&lookup, ok_some(eq(32)));
+/// # }
+pub fn ok_some<'a, T:'a + Debug, E:'a + Debug>(matcher: Box<Matcher<T> + 'a>) -> Box<Matcher<Result<Option<T>,E>> + 'a> {
+    Box::new(move |maybe_actual: &Result<Option<T>,E>| {
+        match *maybe_actual {
+            Ok(Some(ref actual)) => matcher.check(actual),
+            Ok(None) => MatchResultBuilder::for_("ok_some")
+                                           .failed_because("passed Result is Ok but contains None; cannot evaluate nested matcher"),
+            Err(ref err) => MatchResultBuilder::for_("ok_some")
+                                               .failed_because(&format!("passed Result is Err({:?}); cannot evaluate nested matcher", err))
+        }
+    })
+}
+
+/// Matches if applying the labelled `steps` one after another to the asserted value yields `Ok`.
+///
+/// The asserted value is cloned and passed to the first step, each further step receives the `Ok` value of its predecessor.
+/// The pipeline stops at the first step returning `Err`.
+/// On failure the number and label of the failing step are reported together with its error.
+///
+/// #Examples
+/// ```rust
+/// # #[macro_use] extern crate galvanic_assert;
+/// use galvanic_assert::matchers::variant::*;
+/// # fn main() {
+/// assert_that!(&"42".to_owned(), pipeline_succeeds(vec![
+///     ("trim", Box::new(|s: String| Ok(s.trim().to_owned()))),
+///     ("check digits", Box::new(|s: String| if s.chars().all(|c| c.is_digit(10)) { Ok(s) }
+///                                           else { Err(format!("{:?} is not a number", s)) })),
+/// ]));
+/// # }
+pub fn pipeline_succeeds<'a, T:'a + Clone, E:'a + Debug>(steps: Vec<(&'a str, Box<Fn(T) -> Result<T,E> + 'a>)>)
+    -> Box<Matcher<T> + 'a> {
+    Box::new(move |actual: &T| {
+        let builder = MatchResultBuilder::for_("pipeline_succeeds");
+        let mut value = actual.clone();
+        for (idx, &(label, ref step)) in steps.iter().enumerate() {
+            value = match step(value) {
+                Ok(next) => next,
+                Err(err) => return builder.failed_because(
+                    &format!("step {} '{}' failed with Err({:?})", idx + 1, label, err)
+                )
+            };
         }
+        builder.matched()
     })
 }
@@ -48,6 +48,66 @@ mod contains_in_any_order {
     }
 }
 
+mod contains_in_any_order_matching {
+    use super::contains_in_any_order_matching;
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![1,2,3,4], contains_in_any_order_matching(vec![eq(3), gt(3), lt(2), any_value()]));
+    }
+
+    #[test]
+    fn should_match_by_reassigning_elements() {
+        // a greedy assignment of 2 to `gt(1)` would leave `eq(2)` unsatisfied
+        assert_that!(&vec![2,3], contains_in_any_order_matching(vec![gt(1), eq(2)]));
+    }
+
+    #[test]
+    fn should_match_empty_collection() {
+        assert_that!(&Vec::<i32>::new(), contains_in_any_order_matching(Vec::new()));
+    }
+
+    #[test]
+    fn should_fail_due_to_unsatisfied_matcher() {
+        assert_that!(
+            assert_that!(&vec![1,2,3], contains_in_any_order_matching(vec![gt(2), gt(2), any_value()])),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_due_to_unexpected_element() {
+        assert_that!(
+            assert_that!(&vec![1,2,3], contains_in_any_order_matching(vec![eq(1), eq(2)])),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_report_unsatisfied_matchers() {
+        assert_that!(
+            assert_that!(&vec![1,2,3], contains_in_any_order_matching(vec![gt(2), gt(2), any_value()])),
+            panics as payload => {
+                let message = galvanic_assert::panic_message(&*payload).unwrap();
+                assert_that!(message.contains("matchers: #1 'greater_than'"));
+            }
+        );
+    }
+
+    #[test]
+    fn should_report_unsatisfied_matchers_of_empty_collection() {
+        assert_that!(
+            assert_that!(&Vec::<i32>::new(), contains_in_any_order_matching(vec![gt(2), eq(1)])),
+            panics as payload => {
+                let message = galvanic_assert::panic_message(&*payload).unwrap();
+                assert_that!(message.contains("contains no distinct elements for the matchers: #0, #1"));
+                assert_that!(!message.contains("''"));
+            }
+        );
+    }
+}
+
 mod contains_in_order {
     use super::contains_in_order;
 
@@ -86,6 +146,95 @@ mod contains_in_order {
     }
 }
 
+mod is_prefix_of {
+    use super::is_prefix_of;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![1,2], is_prefix_of(vec![1,2,3,4]));
+        assert_that!(&vec![1,2,3,4], is_prefix_of(vec![1,2,3,4]));
+    }
+
+    #[test]
+    fn should_match_empty_collection() {
+        assert_that!(&Vec::new(), is_prefix_of::<i32, Vec<i32>, Vec<i32>>(vec![1,2]));
+    }
+
+    #[test]
+    fn should_fail_due_to_longer_collection() {
+        assert_that!(
+            assert_that!(&vec![1,2,3,4,5], is_prefix_of(vec![1,2,3,4])),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_due_to_differing_element() {
+        assert_that!(
+            assert_that!(&vec![1,3], is_prefix_of(vec![1,2,3,4])),
+            panics
+        );
+    }
+}
+
+mod starts_with_sequence {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![1,2,3,4], starts_with_sequence(vec![1,2]));
+        assert_that!(&vec![1,2], starts_with_sequence(vec![1,2]));
+        assert_that!(&vec![1,2], starts_with_sequence(vec![]));
+    }
+
+    #[test]
+    fn should_fail_due_to_divergence() {
+        assert_that!(
+            assert_that!(&vec![1,3,2,4], starts_with_sequence(vec![1,2])),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_due_to_short_collection() {
+        assert_that!(
+            assert_that!(&vec![1], starts_with_sequence(vec![1,2])),
+            panics
+        );
+    }
+}
+
+mod is_suffix_of {
+    use super::is_suffix_of;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![3,4], is_suffix_of(vec![1,2,3,4]));
+        assert_that!(&vec![1,2,3,4], is_suffix_of(vec![1,2,3,4]));
+    }
+
+    #[test]
+    fn should_match_empty_collection() {
+        assert_that!(&Vec::new(), is_suffix_of::<i32, Vec<i32>, Vec<i32>>(vec![1,2]));
+    }
+
+    #[test]
+    fn should_fail_due_to_longer_collection() {
+        assert_that!(
+            assert_that!(&vec![0,1,2,3,4], is_suffix_of(vec![1,2,3,4])),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_due_to_differing_element() {
+        assert_that!(
+            assert_that!(&vec![1,2], is_suffix_of(vec![1,2,3,4])),
+            panics
+        );
+    }
+}
+
 mod contains_subset {
     use super::contains_subset;
 
@@ -130,6 +279,57 @@ mod contained_in {
     }
 }
 
+mod equals_when_sorted {
+    use super::equals_when_sorted;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![3,1,2,1], equals_when_sorted(vec![1,1,2,3]));
+        assert_that!(&Vec::<i32>::new(), equals_when_sorted(vec![]));
+    }
+
+    #[test]
+    fn should_fail_due_to_different_duplicates() {
+        assert_that!(
+            assert_that!(&vec![1,2,2], equals_when_sorted(vec![1,1,2])),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_due_to_missing_element() {
+        assert_that!(
+            assert_that!(&vec![3,1], equals_when_sorted(vec![1,2,3])),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_not_modify_actual() {
+        let actual = vec![3,1,2];
+        assert_that!(&actual, equals_when_sorted(vec![1,2,3]));
+        assert_that!(&actual, super::contains_in_order(vec![3,1,2]));
+    }
+}
+
+mod is_disjoint_from {
+    use super::is_disjoint_from;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![1,3,5], is_disjoint_from(vec![2,4,6]));
+        assert_that!(&Vec::<i32>::new(), is_disjoint_from(vec![2,4,6]));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&vec![1,2,3], is_disjoint_from(vec![3,4,1])),
+            panics
+        );
+    }
+}
+
 mod sorted_by {
     use super::sorted_by;
     use std::cmp::Ordering;
@@ -460,109 +660,887 @@ mod sorted_strictly_descending {
     }
 }
 
-mod all_elements_satisfy {
-    use super::all_elements_satisfy;
+mod pairwise_satisfy {
+    use super::*;
+    use galvanic_assert::MatchResult;
 
     #[test]
     fn should_match() {
-        assert_that!(&vec![2,4,6,8], all_elements_satisfy(|x| x % 2 == 0));
-    }
-
-    #[test]
-    fn should_match_empty_collection() {
-        assert_that!(&Vec::new(), all_elements_satisfy(|x| x % 2 == 0));
+        assert_that!(&vec![1,2,6,12,24], pairwise_satisfy(|a: &i32, b: &i32| b % a == 0));
+        assert_that!(&vec![3], pairwise_satisfy(|_: &i32, _: &i32| false));
+        assert_that!(&Vec::<i32>::new(), pairwise_satisfy(|_: &i32, _: &i32| false));
     }
 
     #[test]
     fn should_fail() {
         assert_that!(
-            assert_that!(&vec![2,4,6,8], all_elements_satisfy(|x| x % 2 == 1)),
+            assert_that!(&vec![10,15,30,12], pairwise_satisfy(|a: &i32, b: &i32| (a - b).abs() <= 10)),
             panics
         );
     }
+
+    #[test]
+    fn should_report_violating_pair() {
+        match pairwise_satisfy(|a: &i32, b: &i32| (a - b).abs() <= 10).check(&vec![10,15,30,12]) {
+            MatchResult::Failed { reason, .. } => assert_that!(reason.contains("(15, 30) at index 1")),
+            MatchResult::Matched { .. } => panic!("should have failed")
+        }
+    }
 }
 
-mod some_elements_satisfy {
-    use super::some_elements_satisfy;
+mod non_decreasing_by_key {
+    use super::non_decreasing_by_key;
 
     #[test]
     fn should_match() {
-        assert_that!(&vec![2,3,6,8], some_elements_satisfy(|x| x % 2 == 1));
+        assert_that!(&vec![(1,'a'),(2,'b'),(2,'c'),(4,'d')], non_decreasing_by_key(|e: &(i32,char)| e.0));
     }
 
     #[test]
-    fn should_fail_empty_collection() {
+    fn should_match_empty_collection() {
+        assert_that!(&Vec::<(i32,char)>::new(), non_decreasing_by_key(|e: &(i32,char)| e.0));
+    }
+
+    #[test]
+    fn should_fail_unordered() {
         assert_that!(
-            assert_that!(&Vec::new(), some_elements_satisfy(|x| x % 2 == 0)),
+            assert_that!(&vec![(1,'a'),(3,'b'),(2,'c')], non_decreasing_by_key(|e: &(i32,char)| e.0)),
             panics
         );
     }
+}
+
+mod non_increasing_by_key {
+    use super::non_increasing_by_key;
 
     #[test]
-    fn should_fail() {
+    fn should_match() {
+        assert_that!(&vec![(4,'a'),(2,'b'),(2,'c'),(1,'d')], non_increasing_by_key(|e: &(i32,char)| e.0));
+    }
+
+    #[test]
+    fn should_match_empty_collection() {
+        assert_that!(&Vec::<(i32,char)>::new(), non_increasing_by_key(|e: &(i32,char)| e.0));
+    }
+
+    #[test]
+    fn should_fail_unordered() {
         assert_that!(
-            assert_that!(&vec![2,4,6,8], some_elements_satisfy(|x| x % 2 == 1)),
+            assert_that!(&vec![(3,'a'),(1,'b'),(2,'c')], non_increasing_by_key(|e: &(i32,char)| e.0)),
             panics
         );
     }
 }
 
-mod has_entry {
-    mod ordered_map {
-        use std::collections::BTreeMap;
-        use super::super::has_entry;
+mod is_consecutive_from {
+    use super::is_consecutive_from;
 
-        #[test]
-        fn should_match() {
-            let mut map = BTreeMap::<i32,i32>::new();
-            map.insert(1, 2);
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![3,4,5,6], is_consecutive_from(3));
+    }
 
-            assert_that!(&map, has_entry(1, 2));
-        }
+    #[test]
+    fn should_match_empty_collection() {
+        assert_that!(&Vec::<u32>::new(), is_consecutive_from(3));
+    }
 
-        #[test]
-        fn should_fail_due_to_missing_key() {
-            let map = BTreeMap::<i32,i32>::new();
+    #[test]
+    fn should_match_sequence_ending_at_maximum() {
+        assert_that!(&vec![254u8, 255u8], is_consecutive_from(254u8));
+    }
 
-            assert_that!(
-                assert_that!(&map, has_entry(1, 2)),
-                panics
-            );
-        }
+    #[test]
+    fn should_fail_instead_of_overflowing() {
+        assert_that!(
+            assert_that!(&vec![255u8, 0u8], is_consecutive_from(255u8)),
+            panics as payload => {
+                let message = galvanic_assert::panic_message(&*payload).unwrap();
+                assert_that!(message.contains("would overflow after 255"));
+            }
+        );
+        assert_that!(
+            assert_that!(&vec![u64::max_value(), 0], is_consecutive_from(u64::max_value())),
+            panics
+        );
+    }
 
-        #[test]
-        fn should_fail_due_to_wrong_entry() {
-            let mut map = BTreeMap::<i32,i32>::new();
-            map.insert(1, 1);
+    #[test]
+    fn should_fail_due_to_gap() {
+        assert_that!(
+            assert_that!(&vec![3,4,6], is_consecutive_from(3)),
+            panics
+        );
+    }
 
-            assert_that!(
-                assert_that!(&map, has_entry(1, 2)),
-                panics
-            );
-        }
+    #[test]
+    fn should_fail_due_to_repetition() {
+        assert_that!(
+            assert_that!(&vec![3,4,4,5], is_consecutive_from(3)),
+            panics
+        );
     }
 
-    mod hash_map {
-        use std::collections::HashMap;
-        use super::super::has_entry;
+    #[test]
+    fn should_fail_due_to_wrong_start() {
+        assert_that!(
+            assert_that!(&vec![3,4,5], is_consecutive_from(2)),
+            panics
+        );
+    }
+}
 
-        #[test]
-        fn should_match() {
-            let mut map = HashMap::<i32,i32>::new();
-            map.insert(1, 2);
+mod mapped_equals {
+    use super::*;
 
-            assert_that!(&map, has_entry(1, 2));
-        }
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![(1, "foo"), (2, "bar"), (3, "baz")], mapped_equals(|r: &(i32, &str)| r.0, vec![1, 2, 3]));
+    }
 
-        #[test]
-        fn should_fail_due_to_missing_key() {
-            let map = HashMap::<i32,i32>::new();
+    #[test]
+    fn should_fail_due_to_differing_element() {
+        assert_that!(
+            assert_that!(&vec![(1, "foo"), (2, "bar")], mapped_equals(|r: &(i32, &str)| r.1, vec!["foo", "baz"])),
+            panics
+        );
+    }
 
-            assert_that!(
-                assert_that!(&map, has_entry(1, 2)),
-                panics
-            );
-        }
+    #[test]
+    fn should_fail_due_to_shorter_collection() {
+        assert_that!(
+            assert_that!(&vec![(1, "foo")], mapped_equals(|r: &(i32, &str)| r.0, vec![1, 2])),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_due_to_longer_collection() {
+        assert_that!(
+            assert_that!(&vec![(1, "foo"), (2, "bar")], mapped_equals(|r: &(i32, &str)| r.0, vec![1])),
+            panics
+        );
+    }
+}
+
+mod equals_ignoring_order_within_groups {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![(20, "bob"), (20, "alice"), (30, "carol")], equals_ignoring_order_within_groups(
+            vec![(20, "alice"), (20, "bob"), (30, "carol")],
+            |p: &(i32, &str)| p.0
+        ));
+    }
+
+    #[test]
+    fn should_fail_due_to_group_order() {
+        assert_that!(
+            assert_that!(&vec![(30, "carol"), (20, "bob"), (20, "alice")], equals_ignoring_order_within_groups(
+                vec![(20, "alice"), (20, "bob"), (30, "carol")],
+                |p: &(i32, &str)| p.0
+            )),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_due_to_group_membership() {
+        assert_that!(
+            assert_that!(&vec![(20, "bob"), (20, "bob"), (30, "carol")], equals_ignoring_order_within_groups(
+                vec![(20, "alice"), (20, "bob"), (30, "carol")],
+                |p: &(i32, &str)| p.0
+            )),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_due_to_missing_group() {
+        assert_that!(
+            assert_that!(&vec![(20, "bob"), (20, "alice")], equals_ignoring_order_within_groups(
+                vec![(20, "alice"), (20, "bob"), (30, "carol")],
+                |p: &(i32, &str)| p.0
+            )),
+            panics
+        );
+    }
+}
+
+mod is_grouped_by_key {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec!["cat", "cow", "apple", "ant", "bee"], is_grouped_by_key(|w: &&str| w.chars().next()));
+        assert_that!(&Vec::<i32>::new(), is_grouped_by_key(|x: &i32| *x));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&vec![1, 1, 2, 3, 1], is_grouped_by_key(|x: &i32| *x)),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_for_interleaved_keys() {
+        assert_that!(
+            assert_that!(&vec![1, 2, 1, 2], is_grouped_by_key(|x: &i32| x % 2)),
+            panics
+        );
+    }
+}
+
+mod distinct_by_key {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![(1, "foo"), (2, "foo"), (3, "bar")], distinct_by_key(|r: &(i32, &str)| r.0));
+    }
+
+    #[test]
+    fn should_match_empty() {
+        assert_that!(&Vec::<(i32, &str)>::new(), distinct_by_key(|r: &(i32, &str)| r.0));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&vec![(1, "foo"), (2, "bar"), (3, "foo")], distinct_by_key(|r: &(i32, &str)| r.1)),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_extract_each_key_once() {
+        let calls = ::std::cell::Cell::new(0);
+        assert_that!(&vec![1, 2, 3], distinct_by_key(|x: &i32| { calls.set(calls.get() + 1); *x }));
+        assert_that!(calls.get() == 3);
+    }
+}
+
+mod distinct_count_is {
+    use super::*;
+    use galvanic_assert::{Matcher, MatchResult};
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![1,2,2,3,3,3], distinct_count_is(3));
+    }
+
+    #[test]
+    fn should_match_empty() {
+        assert_that!(&Vec::<i32>::new(), distinct_count_is(0));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&vec![1,2,2,3], distinct_count_is(4)),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_report_duplicates() {
+        match distinct_count_is(2).check(&vec!["a", "b", "a", "c", "b"]) {
+            MatchResult::Failed { reason, .. } => {
+                assert_that!(reason.contains("found 3"));
+                assert_that!(reason.contains(r#"["a", "b"]"#));
+            },
+            MatchResult::Matched { .. } => panic!("should have failed")
+        }
+    }
+}
+
+mod all_equal_to {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![0u8; 16], all_equal_to(0));
+    }
+
+    #[test]
+    fn should_fail_for_empty_collection() {
+        assert_that!(
+            assert_that!(&Vec::<u8>::new(), all_equal_to(0)),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_for_differing_element() {
+        assert_that!(
+            assert_that!(&vec![0, 0, 1, 0], all_equal_to(0)),
+            panics
+        );
+    }
+}
+
+mod is_rotation_of {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![3,4,1,2], is_rotation_of(vec![1,2,3,4]));
+        assert_that!(&vec![1,2,3,4], is_rotation_of(vec![1,2,3,4]));
+        assert_that!(&vec![1,1,2,1], is_rotation_of(vec![1,1,1,2]));
+        assert_that!(&Vec::<i32>::new(), is_rotation_of(vec![]));
+    }
+
+    #[test]
+    fn should_fail_due_to_order() {
+        assert_that!(
+            assert_that!(&vec![2,1,3,4], is_rotation_of(vec![1,2,3,4])),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_due_to_length() {
+        assert_that!(
+            assert_that!(&vec![1,2,3,4,1], is_rotation_of(vec![1,2,3,4])),
+            panics
+        );
+    }
+}
+
+mod is_palindrome {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![1,2,3,2,1], is_palindrome());
+        assert_that!(&vec![1,2,2,1], is_palindrome());
+        assert_that!(&Vec::<i32>::new(), is_palindrome());
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&vec![1,2,3,1], is_palindrome()),
+            panics
+        );
+    }
+}
+
+mod all_ok {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        let batch: Vec<Result<i32, String>> = vec![Ok(1), Ok(2)];
+        assert_that!(&batch, all_ok());
+        assert_that!(&Vec::<Result<i32, String>>::new(), all_ok());
+    }
+
+    #[test]
+    fn should_fail() {
+        let batch: Vec<Result<i32, String>> = vec![Ok(1), Err("invalid".to_owned()), Ok(3)];
+        assert_that!(
+            assert_that!(&batch, all_ok()),
+            panics
+        );
+    }
+}
+
+mod all_elements_satisfy {
+    use super::all_elements_satisfy;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![2,4,6,8], all_elements_satisfy(|x| x % 2 == 0));
+    }
+
+    #[test]
+    fn should_match_empty_collection() {
+        assert_that!(&Vec::new(), all_elements_satisfy(|x| x % 2 == 0));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&vec![2,4,6,8], all_elements_satisfy(|x| x % 2 == 1)),
+            panics
+        );
+    }
+}
+
+mod non_empty_and_each {
+    use super::*;
+    use galvanic_assert::matchers::lt;
+    use galvanic_assert::MatchResult;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![2,4,6], non_empty_and_each(lt(10)));
+    }
+
+    #[test]
+    fn should_fail_for_empty_collection() {
+        match non_empty_and_each(lt(10)).check(&Vec::<i32>::new()) {
+            MatchResult::Failed { reason, .. } => assert_that!(reason.contains("the collection is empty")),
+            MatchResult::Matched { .. } => panic!("should have failed")
+        }
+    }
+
+    #[test]
+    fn should_fail_for_failing_element() {
+        match non_empty_and_each(lt(10)).check(&vec![2,12,6]) {
+            MatchResult::Failed { reason, .. } => assert_that!(reason.contains("element 12 at index 1")),
+            MatchResult::Matched { .. } => panic!("should have failed")
+        }
+    }
+}
+
+mod some_elements_satisfy {
+    use super::some_elements_satisfy;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![2,3,6,8], some_elements_satisfy(|x| x % 2 == 1));
+    }
+
+    #[test]
+    fn should_fail_empty_collection() {
+        assert_that!(
+            assert_that!(&Vec::new(), some_elements_satisfy(|x| x % 2 == 0)),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&vec![2,4,6,8], some_elements_satisfy(|x| x % 2 == 1)),
+            panics
+        );
+    }
+}
+
+mod contains_element_displaying {
+    use super::*;
+    use galvanic_assert::MatchResult;
+    use std::fmt;
+
+    struct Opaque { message: &'static str }
+
+    impl fmt::Display for Opaque {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{}", self.message)
+        }
+    }
+
+    #[test]
+    fn should_match() {
+        let items = vec![Opaque { message: "disk full" }, Opaque { message: "read timeout" }];
+        assert_that!(&items, contains_element_displaying("timeout"));
+    }
+
+    #[test]
+    fn should_match_unescaped_message() {
+        let items = vec![Opaque { message: "cannot open \"config\"\nretrying" }];
+        assert_that!(&items, contains_element_displaying("\"config\"\nretrying"));
+    }
+
+    #[test]
+    fn should_fail_for_empty_collection() {
+        assert_that!(
+            assert_that!(&Vec::<Opaque>::new(), contains_element_displaying("timeout")),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&vec!["disk full", "permission denied"], contains_element_displaying("timeout")),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_report_closest_elements() {
+        let items = vec!["a", "tim", "b", "time", "c"];
+        match contains_element_displaying("timeout").check(&items) {
+            MatchResult::Failed { reason, .. } => {
+                assert_that!(reason.contains("closest elements are:\n\ttime\n\ttim\n\ta"));
+            },
+            MatchResult::Matched { .. } => panic!("should have failed")
+        }
+    }
+}
+
+mod has_run_satisfying {
+    use super::has_run_satisfying;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![6,1,6,7,8,1,9], has_run_satisfying(3, |&a| a > 5));
+        assert_that!(&vec![6,7,8], has_run_satisfying(3, |&a| a > 5));
+    }
+
+    #[test]
+    fn should_match_zero_length_run() {
+        assert_that!(&Vec::<i32>::new(), has_run_satisfying(0, |&a| a > 5));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&vec![6,7,1,8,9,1,6], has_run_satisfying(3, |&a| a > 5)),
+            panics
+        );
+    }
+}
+
+mod alternates {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec!["req:1", "resp:1", "req:2"], alternates(|f: &&str| f.starts_with("req"), |f: &&str| f.starts_with("resp")));
+        assert_that!(&Vec::<i32>::new(), alternates(|&x: &i32| x > 0, |&x: &i32| x < 0));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&vec![1, -1, 2, 3], alternates(|&x: &i32| x > 0, |&x: &i32| x < 0)),
+            panics
+        );
+    }
+}
+
+mod no_run_longer_than {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![1,1,1,2,2,1,1,1], no_run_longer_than(3));
+        assert_that!(&Vec::<i32>::new(), no_run_longer_than(0));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&vec![1,2,2,2,2,3], no_run_longer_than(3)),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_for_zero_length() {
+        assert_that!(
+            assert_that!(&vec![1], no_run_longer_than(0)),
+            panics
+        );
+    }
+}
+
+mod partitions_as {
+    use super::partitions_as;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![1,2,3,4,5], partitions_as(|&a| a > 2, 3));
+    }
+
+    #[test]
+    fn should_match_empty_collection() {
+        assert_that!(&Vec::<i32>::new(), partitions_as(|&a| a > 2, 0));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&vec![1,2,3,4,5], partitions_as(|&a| a > 2, 2)),
+            panics
+        );
+    }
+}
+
+mod partition_counts {
+    use super::*;
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![1,2,3,4,5], partition_counts(gt(3), 2, 3));
+        assert_that!(&Vec::<i32>::new(), partition_counts(gt(3), 0, 0));
+    }
+
+    #[test]
+    fn should_fail_due_to_matching_count() {
+        assert_that!(
+            assert_that!(&vec![1,2,3,4,5], partition_counts(gt(2), 2, 3)),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_due_to_nonmatching_count() {
+        assert_that!(
+            assert_that!(&vec![1,2,3,4,5], partition_counts(gt(3), 2, 2)),
+            panics
+        );
+    }
+}
+
+mod sums_to {
+    use super::sums_to;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![1,2,3,4], sums_to(10));
+    }
+
+    #[test]
+    fn should_match_empty_collection() {
+        assert_that!(&Vec::<i32>::new(), sums_to(0));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&vec![1,2,3,4], sums_to(11)),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_instead_of_overflowing() {
+        assert_that!(
+            assert_that!(&vec![i32::max_value(), 1], sums_to(0)),
+            panics as payload => {
+                let message = galvanic_assert::panic_message(&*payload).unwrap();
+                assert_that!(message.contains("overflows at index 1"));
+            }
+        );
+    }
+}
+
+mod sum_close_to {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![0.1; 10], sum_close_to(1.0, 1e-9));
+        assert_that!(&Vec::<f32>::new(), sum_close_to(0.0, 0.0));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&vec![0.5, 0.25], sum_close_to(1.0, 0.1)),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_for_nan() {
+        assert_that!(
+            assert_that!(&vec![1.0, std::f64::NAN], sum_close_to(1.0, 0.1)),
+            panics
+        );
+    }
+}
+
+mod prefix_sums_nonnegative {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![10, -5, 3, -8], prefix_sums_nonnegative());
+        assert_that!(&vec![0.5, -0.5, 1.0], prefix_sums_nonnegative());
+        assert_that!(&Vec::<i32>::new(), prefix_sums_nonnegative());
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&vec![10, -5, 3, -9, 20], prefix_sums_nonnegative()),
+            panics
+        );
+        assert_that!(
+            assert_that!(&vec![-1], prefix_sums_nonnegative()),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_instead_of_overflowing() {
+        assert_that!(
+            assert_that!(&vec![100i8, 100], prefix_sums_nonnegative()),
+            panics as payload => {
+                let message = galvanic_assert::panic_message(&*payload).unwrap();
+                assert_that!(message.contains("overflows at index 1"));
+            }
+        );
+    }
+}
+
+mod has_max {
+    use super::has_max;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![3,1,4,2], has_max(4));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&vec![3,1,4,2], has_max(3)),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_empty_collection() {
+        assert_that!(
+            assert_that!(&Vec::<i32>::new(), has_max(3)),
+            panics
+        );
+    }
+}
+
+mod has_min {
+    use super::has_min;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![3,1,4,2], has_min(1));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&vec![3,1,4,2], has_min(2)),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_empty_collection() {
+        assert_that!(
+            assert_that!(&Vec::<i32>::new(), has_min(3)),
+            panics
+        );
+    }
+}
+
+mod is_max_of {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&4, is_max_of(vec![3,1,4,2]));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&3, is_max_of(vec![3,1,4,2])),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_empty_collection() {
+        assert_that!(
+            assert_that!(&3, is_max_of(Vec::<i32>::new())),
+            panics
+        );
+    }
+}
+
+mod is_min_of {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&1, is_min_of(vec![3,1,4,2]));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&2, is_min_of(vec![3,1,4,2])),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_empty_collection() {
+        assert_that!(
+            assert_that!(&3, is_min_of(Vec::<i32>::new())),
+            panics
+        );
+    }
+}
+
+mod has_entry {
+    mod ordered_map {
+        use std::collections::BTreeMap;
+        use super::super::has_entry;
+
+        #[test]
+        fn should_match() {
+            let mut map = BTreeMap::<i32,i32>::new();
+            map.insert(1, 2);
+
+            assert_that!(&map, has_entry(1, 2));
+        }
+
+        #[test]
+        fn should_fail_due_to_missing_key() {
+            let map = BTreeMap::<i32,i32>::new();
+
+            assert_that!(
+                assert_that!(&map, has_entry(1, 2)),
+                panics
+            );
+        }
+
+        #[test]
+        fn should_fail_due_to_wrong_entry() {
+            let mut map = BTreeMap::<i32,i32>::new();
+            map.insert(1, 1);
+
+            assert_that!(
+                assert_that!(&map, has_entry(1, 2)),
+                panics
+            );
+        }
+    }
+
+    mod hash_map {
+        use std::collections::HashMap;
+        use super::super::has_entry;
+
+        #[test]
+        fn should_match() {
+            let mut map = HashMap::<i32,i32>::new();
+            map.insert(1, 2);
+
+            assert_that!(&map, has_entry(1, 2));
+        }
+
+        #[test]
+        fn should_fail_due_to_missing_key() {
+            let map = HashMap::<i32,i32>::new();
+
+            assert_that!(
+                assert_that!(&map, has_entry(1, 2)),
+                panics
+            );
+        }
 
         #[test]
         fn should_fail_due_to_wrong_entry() {
@@ -676,3 +1654,392 @@ mod has_value {
         }
     }
 }
+
+mod entry {
+    use super::*;
+    use galvanic_assert::matchers::*;
+    use std::collections::HashMap;
+
+    fn map() -> HashMap<&'static str, i32> {
+        let mut map = HashMap::new();
+        map.insert("a", 2);
+        map.insert("b", 5);
+        map
+    }
+
+    #[test]
+    fn should_match() {
+        assert_that!(&map(), entry("a", eq(2)));
+    }
+
+    #[test]
+    fn should_be_composable() {
+        assert_that!(&map(), all_of!(entry("a", gt(0)), entry("b", lt(10))));
+        assert_that!(&map(), any_of!(entry("c", eq(0)), entry("b", eq(5))));
+    }
+
+    #[test]
+    fn should_fail_due_to_value() {
+        assert_that!(
+            assert_that!(&map(), entry("a", eq(3))),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_due_to_missing_key() {
+        assert_that!(
+            assert_that!(&map(), entry("c", any_value())),
+            panics
+        );
+    }
+}
+
+mod all_values_satisfy {
+    use super::all_values_satisfy;
+    use galvanic_assert::matchers::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn should_match() {
+        let map: HashMap<_,_> = vec![(1,2), (2,4), (3,6)].into_iter().collect();
+        assert_that!(&map, all_values_satisfy(lt(10)));
+    }
+
+    #[test]
+    fn should_match_empty_map() {
+        let map = HashMap::<i32,i32>::new();
+        assert_that!(&map, all_values_satisfy(lt(10)));
+    }
+
+    #[test]
+    fn should_fail() {
+        let map: HashMap<_,_> = vec![(1,2), (2,40), (3,6)].into_iter().collect();
+        assert_that!(
+            assert_that!(&map, all_values_satisfy(lt(10))),
+            panics
+        );
+    }
+}
+
+mod all_keys_satisfy {
+    use super::all_keys_satisfy;
+    use galvanic_assert::matchers::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn should_match() {
+        let map: BTreeMap<_,_> = vec![(1,20), (2,40), (3,60)].into_iter().collect();
+        assert_that!(&map, all_keys_satisfy(lt(10)));
+    }
+
+    #[test]
+    fn should_fail() {
+        let map: BTreeMap<_,_> = vec![(1,2), (20,4), (3,6)].into_iter().collect();
+        assert_that!(
+            assert_that!(&map, all_keys_satisfy(lt(10))),
+            panics
+        );
+    }
+}
+
+mod same_num_entries_as {
+    use super::*;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn should_match() {
+        let mut map = HashMap::<i32,i32>::new();
+        map.insert(1, 2);
+        map.insert(2, 5);
+        let mapped: BTreeMap<i32,String> = map.iter().map(|(k, v)| (*k, v.to_string())).collect();
+        assert_that!(&mapped, same_num_entries_as(&map));
+    }
+
+    #[test]
+    fn should_fail() {
+        let mut map = HashMap::<i32,i32>::new();
+        map.insert(1, 2);
+        let mut other = HashMap::<i32,i32>::new();
+        other.insert(1, 2);
+        other.insert(2, 5);
+        assert_that!(
+            assert_that!(&map, same_num_entries_as(&other)),
+            panics
+        );
+    }
+}
+
+mod has_same_keys_as {
+    use super::*;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn should_match() {
+        let mut map = HashMap::<&str,i32>::new();
+        map.insert("foo", 2);
+        map.insert("bar", 5);
+        let mapped: BTreeMap<&str,String> = map.iter().map(|(k, v)| (*k, v.to_string())).collect();
+        assert_that!(&mapped, has_same_keys_as(&map));
+    }
+
+    #[test]
+    fn should_fail() {
+        let mut map = HashMap::<&str,i32>::new();
+        map.insert("foo", 2);
+        map.insert("bar", 5);
+        let mut other = HashMap::<&str,i32>::new();
+        other.insert("foo", 2);
+        other.insert("baz", 5);
+        assert_that!(
+            assert_that!(&map, has_same_keys_as(&other)),
+            panics
+        );
+    }
+}
+
+mod has_min_key {
+    use super::has_min_key;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn should_match() {
+        let map: BTreeMap<_,_> = vec![(3,'c'), (1,'a'), (2,'b')].into_iter().collect();
+        assert_that!(&map, has_min_key(1));
+    }
+
+    #[test]
+    fn should_fail_due_to_other_key() {
+        let map: BTreeMap<_,_> = vec![(3,'c'), (1,'a'), (2,'b')].into_iter().collect();
+        assert_that!(
+            assert_that!(&map, has_min_key(2)),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_due_to_empty_map() {
+        let map = BTreeMap::<i32,char>::new();
+        assert_that!(
+            assert_that!(&map, has_min_key(1)),
+            panics
+        );
+    }
+}
+
+mod has_max_key {
+    use super::has_max_key;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn should_match() {
+        let map: BTreeMap<_,_> = vec![(3,'c'), (1,'a'), (2,'b')].into_iter().collect();
+        assert_that!(&map, has_max_key(3));
+    }
+
+    #[test]
+    fn should_fail_due_to_other_key() {
+        let map: BTreeMap<_,_> = vec![(3,'c'), (1,'a'), (2,'b')].into_iter().collect();
+        assert_that!(
+            assert_that!(&map, has_max_key(2)),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_due_to_empty_map() {
+        let map = BTreeMap::<i32,char>::new();
+        assert_that!(
+            assert_that!(&map, has_max_key(1)),
+            panics
+        );
+    }
+}
+
+mod element_at {
+    use super::element_at;
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![1,2,3], element_at(0, eq(1)));
+        assert_that!(&vec![1,2,3], element_at(2, gt(2)));
+    }
+
+    #[test]
+    fn should_fail_due_to_nonmatching_element() {
+        assert_that!(
+            assert_that!(&vec![1,2,3], element_at(1, eq(3))),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_due_to_index_out_of_bounds() {
+        assert_that!(
+            assert_that!(&vec![1,2,3], element_at(3, any_value())),
+            panics
+        );
+    }
+}
+
+mod first_is {
+    use super::*;
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![1,2,3], first_is(eq(1)));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&vec![1,2,3], first_is(eq(2))),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_for_empty_collection() {
+        assert_that!(
+            assert_that!(&Vec::<i32>::new(), first_is(any_value())),
+            panics
+        );
+    }
+}
+
+mod last_is {
+    use super::*;
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![1,2,3], last_is(eq(3)));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&vec![1,2,3], last_is(eq(2))),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_for_empty_collection() {
+        assert_that!(
+            assert_that!(&Vec::<i32>::new(), last_is(any_value())),
+            panics
+        );
+    }
+}
+
+mod single {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![42], single());
+    }
+
+    #[test]
+    fn should_fail_for_empty_collection() {
+        assert_that!(
+            assert_that!(&Vec::<i32>::new(), single()),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_for_multiple_elements() {
+        assert_that!(
+            assert_that!(&vec![1,2], single()),
+            panics
+        );
+    }
+}
+
+mod single_matching {
+    use super::*;
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![42], single_matching(gt(40)));
+    }
+
+    #[test]
+    fn should_fail_for_nonmatching_element() {
+        assert_that!(
+            assert_that!(&vec![1], single_matching(gt(40))),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_for_multiple_elements() {
+        assert_that!(
+            assert_that!(&vec![41,42], single_matching(gt(40))),
+            panics
+        );
+    }
+}
+
+mod yields_in_order {
+    use super::yields_in_order;
+    use galvanic_assert::matchers::*;
+    use std::cell::{Cell, RefCell};
+    use std::sync::mpsc;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&RefCell::new(vec![1,2,3].into_iter()), yields_in_order(vec![eq(1), eq(2), gt(2)]));
+    }
+
+    #[test]
+    fn should_match_empty_iterator() {
+        assert_that!(&RefCell::new(Vec::<i32>::new().into_iter()), yields_in_order(Vec::new()));
+    }
+
+    #[test]
+    fn should_match_non_clonable_iterator() {
+        let (sender, receiver) = mpsc::channel();
+        sender.send(1).unwrap();
+        sender.send(2).unwrap();
+        drop(sender);
+        assert_that!(&RefCell::new(receiver.iter()), yields_in_order(vec![eq(1), eq(2)]));
+    }
+
+    #[test]
+    fn should_pull_each_item_once() {
+        let pulled = Cell::new(0);
+        let items = RefCell::new((1..4).map(|x| { pulled.set(pulled.get() + 1); x }));
+        assert_that!(&items, yields_in_order(vec![eq(1), eq(2), eq(3)]));
+        assert_that!(&pulled.get(), eq(3));
+    }
+
+    #[test]
+    fn should_fail_due_to_nonmatching_item() {
+        assert_that!(
+            assert_that!(&RefCell::new(1..4), yields_in_order(vec![eq(1), eq(3), eq(3)])),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_due_to_short_iterator() {
+        assert_that!(
+            assert_that!(&RefCell::new(1..3), yields_in_order(vec![eq(1), eq(2), eq(3)])),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_due_to_long_iterator() {
+        assert_that!(
+            assert_that!(&RefCell::new(1..5), yields_in_order(vec![eq(1), eq(2), eq(3)])),
+            panics
+        );
+    }
+}
@@ -84,6 +84,29 @@ mod any_of {
     }
 }
 
+mod matcher_names {
+    use super::*;
+    use galvanic_assert::{Matcher, MatchResult};
+
+    #[test]
+    fn all_of_should_compose_names_on_success() {
+        let matcher = all_of!(gt(0), lt(5));
+        match matcher.check(&1) {
+            MatchResult::Matched { name } => assert_eq!(name, "all_of(greater_than, less_than)"),
+            MatchResult::Failed { .. } => panic!("matcher should succeed")
+        }
+    }
+
+    #[test]
+    fn any_of_should_report_name_of_succeeding_matcher() {
+        let matcher = any_of!(lt(0), eq(1), gt(5));
+        match matcher.check(&1) {
+            MatchResult::Matched { name } => assert_eq!(name, "equal"),
+            MatchResult::Failed { .. } => panic!("matcher should succeed")
+        }
+    }
+}
+
 mod combining_combinators {
     use super::*;
 
@@ -37,209 +37,1112 @@ mod assert_panic {
     }
 }
 
+mod assert_panic_payload {
+    use super::*;
+    use galvanic_assert::panic_message;
+
+    #[test]
+    fn should_bind_panic_payload() {
+        assert_that!(panic!("panic"), panics as payload => {
+            assert_that!(&panic_message(&*payload), eq(Some("panic")));
+        });
+    }
+
+    #[test]
+    fn should_bind_formatted_panic_payload() {
+        assert_that!(panic!("panic {}", 1), panics as payload => {
+            assert_that!(&panic_message(&*payload), eq(Some("panic 1")));
+        });
+    }
+
+    #[test]
+    fn should_fail_to_assert_a_panic() {
+        assert_that!(
+            assert_that!(&1+1, panics as payload => { drop(payload); }),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_if_payload_assertion_fails() {
+        assert_that!(
+            assert_that!(panic!("panic"), panics as payload => {
+                assert_that!(&panic_message(&*payload), eq(Some("other")));
+            }),
+            panics
+        );
+    }
+}
+
 mod assert_does_not_panic {
     use super::*;
 
     #[test]
-    fn should_assert_that_no_panic_occurred() {
-        assert_that!(&1+1, does not panic);
+    fn should_assert_that_no_panic_occurred() {
+        assert_that!(&1+1, does not panic);
+    }
+
+    #[test]
+    fn should_fail_to_assert_a_panic() {
+        let panicked = std::panic::catch_unwind(|| {
+            assert_that!(panic!("panic"), does not panic);
+        });
+        assert!(panicked.is_err());
+    }
+}
+
+mod assert_expression {
+    use super::*;
+    use galvanic_assert::panic_message;
+
+    #[test]
+    fn should_assert_an_expression_to_be_true() {
+        let ok: Result<i32,i32> = Ok(4);
+        assert_that!(ok.is_ok());
+    }
+
+    #[test]
+    fn should_fail_to_assert_an_expression_to_be_true() {
+        let err: Result<i32,i32> = Err(4);
+        assert_that!(
+            assert_that!(err.is_ok()),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_assert_an_expression_to_be_true_with_reason() {
+        let ok: Result<i32,i32> = Ok(4);
+        assert_that!(ok.is_ok(), otherwise "ok is Err");
+    }
+
+    #[test]
+    fn should_fail_to_assert_an_expression_to_be_true_with_reason() {
+        let err: Result<i32,i32> = Err(4);
+        assert_that!(
+            assert_that!(err.is_ok(), otherwise "err is Ok"),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_assert_an_expression_with_captures_to_be_true() {
+        let a = 1;
+        let b = 2;
+        assert_that!(a, b => a < b);
+    }
+
+    #[test]
+    fn should_fail_to_assert_an_expression_with_captures_to_be_true() {
+        let a = 3;
+        let b = 2;
+        assert_that!(assert_that!(a, b => a < b), panics as payload => {
+            let message = panic_message(&*payload).unwrap();
+            assert_that!(message.contains("'a < b' is not true"));
+            assert_that!(message.contains("a = 3"));
+            assert_that!(message.contains("b = 2"));
+        });
+    }
+}
+
+mod invariants {
+    use super::*;
+
+    #[test]
+    fn assertion_should_succeed() {
+        assert_that!(&1, assertion_always_succeeds());
+    }
+
+    #[test]
+    fn assertion_should_fail() {
+        assert_that!(
+            assert_that!(&1, assertion_always_fails()),
+            panics
+        );
+    }
+}
+
+mod not {
+    use super::*;
+
+    #[test]
+    fn should_invert_success() {
+        assert_that!(&1, not(assertion_always_fails()));
+    }
+
+    #[test]
+    fn should_invert_fail() {
+        assert_that!(
+            assert_that!(&1, not(assertion_always_succeeds())),
+            panics
+        );
+    }
+}
+
+mod returning {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![1,2,3], returning(|v: &Vec<i32>| v.len(), eq(3)));
+        assert_that!(&"foo", returning(|s: &&str| s.to_uppercase(), eq("FOO".to_owned())));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&vec![1,2,3], returning(|v: &Vec<i32>| v.len(), eq(2))),
+            panics
+        );
+    }
+}
+
+mod after {
+    use super::*;
+    use galvanic_assert::{Matcher, MatchResult};
+
+    #[test]
+    fn should_match() {
+        assert_that!(&-5, after(|x: &i32| x.abs(), eq(5)));
+        assert_that!(&"foo", after(|s: &&str| s.to_uppercase(), eq("FOO".to_owned())));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&-5, after(|x: &i32| x.abs(), eq(4))),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_report_transformed_value() {
+        let matcher = after(|s: &&str| s.to_uppercase(), eq("BAR".to_owned()));
+        match matcher.check(&"foo") {
+            MatchResult::Failed { reason, .. } => assert_that!(&reason.contains("\"FOO\""), eq(true)),
+            _ => panic!("matcher should fail")
+        }
+    }
+}
+
+mod cloned_then {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        let values = vec![3, 1, 2];
+        assert_that!(&values, cloned_then(|v: &mut Vec<i32>| v.sort(), eq(vec![1, 2, 3])));
+        assert_that!(&values, eq(vec![3, 1, 2]));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&"Foo".to_owned(), cloned_then(|s: &mut String| s.make_ascii_uppercase(), eq("Foo".to_owned()))),
+            panics
+        );
+    }
+}
+
+mod eventually {
+    use super::*;
+    use std::cell::Cell;
+    use std::time::Duration;
+
+    #[test]
+    fn should_match_after_polling() {
+        let polls = Cell::new(0);
+        let poll = || { polls.set(polls.get() + 1); polls.get() };
+        assert_that!(&poll, eventually(eq(5), Duration::from_secs(5), Duration::from_millis(1)));
+        assert_that!(&polls.get(), eq(5));
+    }
+
+    #[test]
+    fn should_fail_after_timeout() {
+        assert_that!(
+            assert_that!(&|| 1, eventually(eq(2), Duration::from_millis(20), Duration::from_millis(5))),
+            panics
+        );
+    }
+}
+
+mod once {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&|| 3, once(all_of!(gt(2), lt(4))));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&|| 3, once(all_of!(gt(2), lt(3)))),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_produce_value_only_once() {
+        let calls = Cell::new(0);
+        let producer = || { calls.set(calls.get() + 1); calls.get() };
+        assert_that!(&producer, once(all_of!(eq(1), lt(2), gt(0))));
+        assert_that!(&calls.get(), eq(1));
+    }
+
+    #[test]
+    fn should_check_value_of_each_producer_when_reused() {
+        let first: &Fn() -> i32 = &|| 1;
+        let second: &Fn() -> i32 = &|| 2;
+        let matcher = once(eq(1));
+        assert_that!(&bool::from(matcher.check(&first)), eq(true));
+        assert_that!(&bool::from(matcher.check(&second)), eq(false));
+    }
+}
+
+mod deterministic {
+    use super::*;
+    use std::cell::Cell;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn should_match() {
+        assert_that!(&|| "foo".len(), deterministic(10));
+    }
+
+    #[test]
+    fn should_match_expression() {
+        assert_that!("foo".to_uppercase(), is deterministic(10));
+    }
+
+    #[test]
+    fn should_fail() {
+        let calls = Cell::new(0);
+        let counter = || { calls.set(calls.get() + 1); calls.get() };
+        assert_that!(&counter, not(deterministic(3)));
+        assert_that!(&calls.get(), eq(3));
+    }
+
+    #[test]
+    fn should_fail_for_expression() {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        assert_that!(
+            assert_that!(COUNTER.fetch_add(1, Ordering::SeqCst), is deterministic(3)),
+            panics
+        );
+    }
+}
+
+mod idempotent_matcher {
+    use super::*;
+    use galvanic_assert::{Matcher, MatchResult};
+    use std::cell::Cell;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&1, idempotent_matcher(eq(1)));
+    }
+
+    #[test]
+    fn should_fail_if_inner_matcher_fails() {
+        assert_that!(
+            assert_that!(&1, idempotent_matcher(eq(2))),
+            panics
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn should_fail_for_stateful_matcher() {
+        let calls = Cell::new(0);
+        let matcher = idempotent_matcher(Box::new(|_: &i32| -> MatchResult {
+            calls.set(calls.get() + 1);
+            (calls.get() == 1).into()
+        }));
+        if let MatchResult::Matched { .. } = matcher.check(&1) {
+            panic!("matcher should fail for diverging results");
+        }
+    }
+
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn should_check_only_once_without_debug_assertions() {
+        let calls = Cell::new(0);
+        let matcher = idempotent_matcher(Box::new(|_: &i32| -> MatchResult {
+            calls.set(calls.get() + 1);
+            (calls.get() == 1).into()
+        }));
+        assert_that!(&bool::from(matcher.check(&1)), eq(true));
+        assert_that!(&calls.get(), eq(1));
+    }
+}
+
+mod eq {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&1, eq(1));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&1, eq(2)), panics
+        );
+    }
+}
+
+mod equal_to_fn {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&2, equal_to_fn(|| 1 + 1));
+    }
+
+    #[test]
+    fn should_evaluate_expected_value_at_check_time() {
+        let state = Cell::new(1);
+        let matcher = equal_to_fn(|| state.get());
+        state.set(3);
+        assert_that!(&3, matcher);
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&1, equal_to_fn(|| 2)),
+            panics
+        );
+    }
+}
+
+mod equal_to_derived {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        let items = vec![1, 2, 3];
+        assert_that!(&3, equal_to_derived(&items, |v: &&Vec<i32>| v.len()));
+        assert_that!(&"FOO".to_owned(), equal_to_derived("foo", |s: &&str| s.to_uppercase()));
+    }
+
+    #[test]
+    fn should_fail() {
+        let items = vec![1, 2, 3];
+        assert_that!(
+            assert_that!(&2, equal_to_derived(&items, |v: &&Vec<i32>| v.len())),
+            panics
+        );
+    }
+}
+
+mod less_than {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&1, less_than(2));
+        assert_that!(&1, lt(2));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&1, less_than(1)),
+            panics
+        );
+        assert_that!(
+            assert_that!(&1, lt(1)),
+            panics
+        );
+    }
+}
+
+mod greater_than {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&1, greater_than(0));
+        assert_that!(&1, gt(0));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&1, greater_than(1)),
+            panics
+        );
+        assert_that!(
+            assert_that!(&1, gt(1)),
+            panics
+        );
+    }
+}
+
+mod less_than_or_equal {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&1, less_than_or_equal(2));
+        assert_that!(&1, less_than_or_equal(1));
+        assert_that!(&1, leq(2));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&1, less_than_or_equal(0)),
+            panics
+        );
+        assert_that!(
+            assert_that!(&1, leq(0)),
+            panics
+        );
+    }
+}
+
+mod greater_than_or_equal {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&1, greater_than_or_equal(0));
+        assert_that!(&1, greater_than_or_equal(1));
+        assert_that!(&1, geq(0));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&1, greater_than_or_equal(2)),
+            panics
+        );
+        assert_that!(
+            assert_that!(&1, geq(2)),
+            panics
+        );
+    }
+}
+
+mod close_to {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&3.14, close_to(3.14, 0.001));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&2.2, close_to(3.14, 0.001)),
+            panics
+        );
+    }
+}
+
+mod close_by {
+    use super::*;
+
+    fn manhattan(a: &(i32, i32), b: &(i32, i32)) -> f64 {
+        ((a.0 - b.0).abs() + (a.1 - b.1).abs()) as f64
+    }
+
+    #[test]
+    fn should_match() {
+        assert_that!(&(1, 2), close_by((2, 3), manhattan, 2.0));
+        assert_that!(&(1, 2), close_by((1, 2), manhattan, 0.0));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&(1, 2), close_by((2, 4), manhattan, 2.0)),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_for_nan_distance() {
+        assert_that!(
+            assert_that!(&1.0, close_by(1.0, |_: &f64, _: &f64| std::f64::NAN, 1.0)),
+            panics
+        );
+    }
+}
+
+mod in_any_range {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&5, in_any_range(vec![(1, 2), (4, 6)]));
+        assert_that!(&4, in_any_range(vec![(1, 2), (4, 6)]));
+        assert_that!(&2, in_any_range(vec![(1, 2), (4, 6)]));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&3, in_any_range(vec![(1, 2), (4, 6)])),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_without_ranges() {
+        assert_that!(
+            assert_that!(&3, in_any_range(Vec::new())),
+            panics
+        );
+    }
+}
+
+mod between_values {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        let lower = 0.5 * 10.0;
+        let upper = 2.0 * 10.0;
+        assert_that!(&12.5, between_values(&lower, &upper));
+        assert_that!(&5.0, between_values(&lower, &upper));
+        assert_that!(&9, between_values(7, 9));
+    }
+
+    #[test]
+    fn should_fail_below() {
+        assert_that!(
+            assert_that!(&6, between_values(7, 9)),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_above() {
+        let lower = 1;
+        let upper = 2;
+        assert_that!(
+            assert_that!(&3, between_values(&lower, &upper)),
+            panics
+        );
+    }
+}
+
+mod abs_less_than {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&-0.001, abs_less_than(0.01));
+        assert_that!(&0.001, abs_less_than(0.01));
+        assert_that!(&-3, abs_less_than(4));
+    }
+
+    #[test]
+    fn should_fail_for_negative_value() {
+        assert_that!(
+            assert_that!(&-4, abs_less_than(4)),
+            panics
+        );
+        assert_that!(
+            assert_that!(&i32::min_value(), abs_less_than(4)),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_for_positive_value() {
+        assert_that!(
+            assert_that!(&0.5, abs_less_than(0.1)),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_report_absolute_value() {
+        use galvanic_assert::MatchResult;
+        match abs_less_than(4).check(&-7) {
+            MatchResult::Matched { .. } => panic!("matcher should fail"),
+            MatchResult::Failed { reason, .. } => assert_that!(reason.contains("the absolute value of -7 is 7 "))
+        }
+        match abs_less_than(4).check(&i32::min_value()) {
+            MatchResult::Matched { .. } => panic!("matcher should fail"),
+            MatchResult::Failed { reason, .. } => assert_that!(reason.contains("is not representable"))
+        }
+    }
+
+    #[test]
+    fn should_fail_for_non_positive_bound() {
+        assert_that!(&0, not(abs_less_than(0)));
+        assert_that!(&0, not(abs_less_than(-4)));
+        assert_that!(&0.0, not(abs_less_than(::std::f64::NAN)));
+    }
+}
+
+mod consistent_ordering {
+    use super::*;
+    use std::cmp::Ordering;
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct AlwaysLess(i32);
+
+    impl Ord for AlwaysLess {
+        fn cmp(&self, _other: &AlwaysLess) -> Ordering { Ordering::Less }
+    }
+
+    impl PartialOrd for AlwaysLess {
+        fn partial_cmp(&self, other: &AlwaysLess) -> Option<Ordering> { Some(self.cmp(other)) }
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct NeverLess(i32);
+
+    impl Ord for NeverLess {
+        fn cmp(&self, other: &NeverLess) -> Ordering {
+            if self.0 == other.0 { Ordering::Equal } else { Ordering::Greater }
+        }
+    }
+
+    impl PartialOrd for NeverLess {
+        fn partial_cmp(&self, other: &NeverLess) -> Option<Ordering> { Some(self.cmp(other)) }
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct ByKeyOnly { key: i32, payload: &'static str }
+
+    impl Ord for ByKeyOnly {
+        fn cmp(&self, other: &ByKeyOnly) -> Ordering { self.key.cmp(&other.key) }
+    }
+
+    impl PartialOrd for ByKeyOnly {
+        fn partial_cmp(&self, other: &ByKeyOnly) -> Option<Ordering> { Some(self.cmp(other)) }
+    }
+
+    #[test]
+    fn should_match() {
+        assert_that!(&"apple", consistent_ordering("banana"));
+        assert_that!(&(1, 'b'), consistent_ordering((1, 'b')));
+        assert_that!(&ByKeyOnly { key: 1, payload: "x" }, consistent_ordering(ByKeyOnly { key: 2, payload: "y" }));
+    }
+
+    #[test]
+    fn should_fail_for_non_reflexive_ordering() {
+        assert_that!(
+            assert_that!(&AlwaysLess(1), consistent_ordering(AlwaysLess(2))),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_for_non_antisymmetric_ordering() {
+        assert_that!(
+            assert_that!(&NeverLess(1), consistent_ordering(NeverLess(2))),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_for_ordering_inconsistent_with_equality() {
+        assert_that!(
+            assert_that!(&ByKeyOnly { key: 1, payload: "x" }, consistent_ordering(ByKeyOnly { key: 1, payload: "y" })),
+            panics
+        );
+    }
+}
+
+mod rounds_to {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&2.5, rounds_to(3));
+        assert_that!(&2.49, rounds_to(2));
+        assert_that!(&-2.5f32, rounds_to(-3));
     }
 
     #[test]
-    fn should_fail_to_assert_a_panic() {
-        let panicked = std::panic::catch_unwind(|| {
-            assert_that!(panic!("panic"), does not panic);
-        });
-        assert!(panicked.is_err());
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&2.5, rounds_to(2)),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_compare_as_integers() {
+        assert_that!(&9007199254740992.0, rounds_to(9007199254740992));
+        assert_that!(&9007199254740992.0, not(rounds_to(9007199254740993)));
+    }
+
+    #[test]
+    fn should_fail_for_nan_and_out_of_range_values() {
+        use std::f64;
+        assert_that!(&f64::NAN, not(rounds_to(0)));
+        assert_that!(&f64::INFINITY, not(rounds_to(i64::max_value())));
+        assert_that!(&1e19, not(rounds_to(i64::max_value())));
+        assert_that!(&-9223372036854775808.0, rounds_to(i64::min_value()));
     }
 }
 
-mod assert_expression {
+mod floors_to {
     use super::*;
 
     #[test]
-    fn should_assert_an_expression_to_be_true() {
-        let ok: Result<i32,i32> = Ok(4);
-        assert_that!(ok.is_ok());
+    fn should_match() {
+        assert_that!(&2.9, floors_to(2));
+        assert_that!(&-2.1, floors_to(-3));
     }
 
     #[test]
-    fn should_fail_to_assert_an_expression_to_be_true() {
-        let err: Result<i32,i32> = Err(4);
+    fn should_fail() {
         assert_that!(
-            assert_that!(err.is_ok()),
+            assert_that!(&2.9, floors_to(3)),
             panics
         );
     }
+}
+
+mod ceils_to {
+    use super::*;
 
     #[test]
-    fn should_assert_an_expression_to_be_true_with_reason() {
-        let ok: Result<i32,i32> = Ok(4);
-        assert_that!(ok.is_ok(), otherwise "ok is Err");
+    fn should_match() {
+        assert_that!(&2.1, ceils_to(3));
+        assert_that!(&-2.9, ceils_to(-2));
     }
 
     #[test]
-    fn should_fail_to_assert_an_expression_to_be_true_with_reason() {
-        let err: Result<i32,i32> = Err(4);
+    fn should_fail() {
         assert_that!(
-            assert_that!(err.is_ok(), otherwise "err is Ok"),
+            assert_that!(&2.1, ceils_to(2)),
             panics
         );
     }
 }
 
-mod invariants {
+mod total_eq {
     use super::*;
+    use std::{f32, f64};
 
     #[test]
-    fn assertion_should_succeed() {
-        assert_that!(&1, assertion_always_succeeds());
+    fn should_match() {
+        assert_that!(&1.5, total_eq(1.5));
+        assert_that!(&f64::NAN, total_eq(f64::NAN));
+        assert_that!(&f32::NAN, total_eq(f32::NAN));
+        assert_that!(&-0.0f32, total_eq(-0.0));
     }
 
     #[test]
-    fn assertion_should_fail() {
+    fn should_fail_for_signed_zeros() {
         assert_that!(
-            assert_that!(&1, assertion_always_fails()),
+            assert_that!(&0.0, total_eq(-0.0)),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_for_different_values() {
+        assert_that!(
+            assert_that!(&1.0f32, total_eq(1.0000001)),
             panics
         );
     }
 }
 
-mod not {
+mod is_integral {
     use super::*;
+    use std::f64;
 
     #[test]
-    fn should_invert_success() {
-        assert_that!(&1, not(assertion_always_fails()));
+    fn should_match() {
+        assert_that!(&3.0, is_integral());
+        assert_that!(&-2.0f32, is_integral());
+        assert_that!(&0.0, is_integral());
     }
 
     #[test]
-    fn should_invert_fail() {
+    fn should_fail() {
         assert_that!(
-            assert_that!(&1, not(assertion_always_succeeds())),
+            assert_that!(&2.5, is_integral()),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_for_non_finite_values() {
+        assert_that!(
+            assert_that!(&f64::NAN, is_integral()),
+            panics
+        );
+        assert_that!(
+            assert_that!(&f64::INFINITY, is_integral()),
             panics
         );
     }
 }
 
-mod eq {
+mod maps_to_finite {
     use super::*;
 
     #[test]
     fn should_match() {
-        assert_that!(&1, eq(1));
+        assert_that!(&1e-300, maps_to_finite(|x: &f64| x.ln()));
+        assert_that!(&vec![1.0, 2.0], maps_to_finite(|v: &Vec<f64>| v.iter().sum::<f64>() / v.len() as f64));
     }
 
     #[test]
-    fn should_fail() {
+    fn should_fail_for_infinite_result() {
         assert_that!(
-            assert_that!(&1, eq(2)), panics
+            assert_that!(&0.0, maps_to_finite(|x: &f64| x.ln())),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_for_nan_result() {
+        assert_that!(
+            assert_that!(&-1.0, maps_to_finite(|x: &f64| x.sqrt())),
+            panics
+        );
+        assert_that!(
+            assert_that!(&Vec::<f64>::new(), maps_to_finite(|v: &Vec<f64>| v.iter().sum::<f64>() / v.len() as f64)),
+            panics
         );
     }
 }
 
-mod less_than {
+mod has_significant_digits {
     use super::*;
+    use std::f64;
 
     #[test]
     fn should_match() {
-        assert_that!(&1, less_than(2));
-        assert_that!(&1, lt(2));
+        assert_that!(&3.14, has_significant_digits(3));
+        assert_that!(&-0.0250, has_significant_digits(2));
+        assert_that!(&1200.0, has_significant_digits(2));
+        assert_that!(&1.0e-7, has_significant_digits(1));
+        assert_that!(&0.1f32, has_significant_digits(1));
+        assert_that!(&0.0, has_significant_digits(1));
     }
 
     #[test]
     fn should_fail() {
         assert_that!(
-            assert_that!(&1, less_than(1)),
+            assert_that!(&(0.1 + 0.2), has_significant_digits(1)),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_for_non_finite_values() {
+        assert_that!(
+            assert_that!(&f64::NAN, has_significant_digits(1)),
             panics
         );
         assert_that!(
-            assert_that!(&1, lt(1)),
+            assert_that!(&f64::INFINITY, has_significant_digits(1)),
             panics
         );
     }
 }
 
-mod greater_than {
+mod duration_close_to {
     use super::*;
+    use std::time::Duration;
 
     #[test]
     fn should_match() {
-        assert_that!(&1, greater_than(0));
-        assert_that!(&1, gt(0));
+        assert_that!(&Duration::from_millis(104), duration_close_to(Duration::from_millis(100), Duration::from_millis(5)));
+        assert_that!(&Duration::from_millis(95), duration_close_to(Duration::from_millis(100), Duration::from_millis(5)));
     }
 
     #[test]
-    fn should_fail() {
+    fn should_fail_if_too_long() {
         assert_that!(
-            assert_that!(&1, greater_than(1)),
+            assert_that!(&Duration::from_millis(106), duration_close_to(Duration::from_millis(100), Duration::from_millis(5))),
             panics
         );
+    }
+
+    #[test]
+    fn should_fail_if_too_short() {
         assert_that!(
-            assert_that!(&1, gt(1)),
+            assert_that!(&Duration::from_millis(0), duration_close_to(Duration::from_millis(100), Duration::from_millis(5))),
             panics
         );
     }
 }
 
-mod less_than_or_equal {
+mod is_nonzero_duration {
     use super::*;
+    use std::time::Duration;
 
     #[test]
     fn should_match() {
-        assert_that!(&1, less_than_or_equal(2));
-        assert_that!(&1, less_than_or_equal(1));
-        assert_that!(&1, leq(2));
+        assert_that!(&Duration::from_nanos(1), is_nonzero_duration());
+        assert_that!(&Duration::from_secs(3), is_nonzero_duration());
     }
 
     #[test]
     fn should_fail() {
         assert_that!(
-            assert_that!(&1, less_than_or_equal(0)),
+            assert_that!(&Duration::from_millis(0), is_nonzero_duration()),
+            panics
+        );
+    }
+}
+
+mod monotone_checker {
+    use super::*;
+    use galvanic_assert::Matcher;
+
+    #[test]
+    fn should_match_increasing() {
+        let checker = MonotoneChecker::increasing();
+        assert_that!(&0.1, &checker);
+        assert_that!(&0.2, &checker);
+        assert_that!(&2.5, &checker);
+    }
+
+    #[test]
+    fn should_fail_increasing_for_repeated_value() {
+        let checker = MonotoneChecker::increasing();
+        assert_that!(&0.1, &checker);
+        assert_that!(&bool::from(checker.check(&0.1)), eq(false));
+    }
+
+    #[test]
+    fn should_match_non_decreasing() {
+        let checker = MonotoneChecker::non_decreasing();
+        assert_that!(&0.1, &checker);
+        assert_that!(&0.1, &checker);
+        assert_that!(&2.5, &checker);
+    }
+
+    #[test]
+    fn should_fail_non_decreasing() {
+        let checker = MonotoneChecker::non_decreasing();
+        assert_that!(&0.2, &checker);
+        assert_that!(&bool::from(checker.check(&0.1)), eq(false));
+    }
+
+    #[test]
+    fn should_compare_against_last_value_after_failure() {
+        let checker = MonotoneChecker::non_decreasing();
+        assert_that!(&0.5, &checker);
+        assert_that!(&bool::from(checker.check(&0.1)), eq(false));
+        assert_that!(&0.2, &checker);
+    }
+}
+
+mod is_power_of_two {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&1u32, is_power_of_two());
+        assert_that!(&1024usize, is_power_of_two());
+        assert_that!(&128u8, is_power_of_two());
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&12u32, is_power_of_two()),
             panics
         );
+    }
+
+    #[test]
+    fn should_fail_for_zero() {
         assert_that!(
-            assert_that!(&1, leq(0)),
+            assert_that!(&0u32, is_power_of_two()),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_for_negative_values() {
+        assert_that!(
+            assert_that!(&std::i32::MIN, is_power_of_two()),
             panics
         );
     }
 }
 
-mod greater_than_or_equal {
+mod has_bit_set {
     use super::*;
 
     #[test]
     fn should_match() {
-        assert_that!(&1, greater_than_or_equal(0));
-        assert_that!(&1, greater_than_or_equal(1));
-        assert_that!(&1, geq(0));
+        assert_that!(&0b1000u8, has_bit_set(3));
+        assert_that!(&-1i64, has_bit_set(63));
     }
 
     #[test]
     fn should_fail() {
         assert_that!(
-            assert_that!(&1, greater_than_or_equal(2)),
+            assert_that!(&0b1000u8, has_bit_set(2)),
             panics
         );
+    }
+
+    #[test]
+    fn should_fail_for_bit_beyond_width() {
         assert_that!(
-            assert_that!(&1, geq(2)),
+            assert_that!(&0xffu8, has_bit_set(8)),
             panics
         );
     }
 }
 
-mod close_to {
+mod has_flags {
     use super::*;
 
     #[test]
     fn should_match() {
-        assert_that!(&3.14, close_to(3.14, 0.001));
+        assert_that!(&0b1011u32, has_flags(0b0011));
+        assert_that!(&0b1011u32, has_flags(0));
     }
 
     #[test]
     fn should_fail() {
         assert_that!(
-            assert_that!(&2.2, close_to(3.14, 0.001)),
+            assert_that!(&0b1011u32, has_flags(0b0110)),
+            panics
+        );
+    }
+}
+
+mod fits_in {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&255u64, fits_in::<u8, _>());
+        assert_that!(&-128i64, fits_in::<i8, _>());
+        assert_that!(&u32::max_value(), fits_in::<u64, _>());
+    }
+
+    #[test]
+    fn should_fail_above_range() {
+        assert_that!(
+            assert_that!(&256u64, fits_in::<u8, _>()),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_below_range() {
+        assert_that!(
+            assert_that!(&-1i32, fits_in::<u32, _>()),
             panics
         );
     }
@@ -268,6 +1171,27 @@ mod same_object {
     }
 }
 
+mod is_type {
+    use super::*;
+    use std::any::Any;
+
+    #[test]
+    fn should_match() {
+        let boxed: Box<Any> = Box::new(42i32);
+        assert_that!(&boxed, is_type::<i32, _>());
+        let value = "foo".to_owned();
+        let any: &Any = &value;
+        assert_that!(&any, is_type::<String, _>());
+    }
+
+    #[test]
+    fn should_fail() {
+        let boxed: Box<Any> = Box::new(42i32);
+        // `Any` is not unwind safe, so the failure cannot be asserted with `panics`
+        assert_that!(&boxed, not(is_type::<i64, _>()));
+    }
+}
+
 mod has_structure {
     use super::*;
 
@@ -302,6 +1226,57 @@ mod has_structure {
         }
     }
 
+    mod nested {
+        use super::*;
+        use galvanic_assert::{Matcher, MatchResult};
+
+        struct Inner { x: i32 }
+        struct Middle { inner: Inner }
+        struct Outer { middle: Middle, y: i32 }
+
+        #[test]
+        fn should_match() {
+            let outer = Outer { middle: Middle { inner: Inner { x: 1 } }, y: 2 };
+            assert_that!(&outer, has_structure!(Outer {
+                middle: has_structure!(Middle {
+                    inner: has_structure!(Inner { x: eq(1) })
+                }),
+                y: eq(2)
+            }));
+        }
+
+        #[test]
+        fn should_report_full_field_path() {
+            let outer = Outer { middle: Middle { inner: Inner { x: 1 } }, y: 2 };
+            let matcher: Box<Matcher<Outer>> = has_structure!(Outer {
+                middle: has_structure!(Middle {
+                    inner: has_structure!(Inner { x: eq(2) })
+                }),
+                y: eq(2)
+            });
+            match matcher.check(&outer) {
+                MatchResult::Failed { reason, .. } => {
+                    assert!(reason.contains("field 'middle.inner.x'"));
+                    assert!(!reason.contains("field 'y'"));
+                },
+                MatchResult::Matched { .. } => panic!("matcher should fail")
+            }
+        }
+
+        #[test]
+        fn should_report_full_field_path_of_tuple_like() {
+            struct Pair(i32, i32);
+            struct Wrapper { pair: Pair }
+            let matcher: Box<Matcher<Wrapper>> = has_structure!(Wrapper {
+                pair: structure!(Pair [eq(1), eq(3)])
+            });
+            match matcher.check(&Wrapper { pair: Pair(1, 2) }) {
+                MatchResult::Failed { reason, .. } => assert!(reason.contains("field 'pair.x'")),
+                MatchResult::Matched { .. } => panic!("matcher should fail")
+            }
+        }
+    }
+
     mod tuple_like {
         use super::*;
         struct Bar(i32, f64);
@@ -367,3 +1342,123 @@ mod has_structure {
         }
     }
 }
+
+mod matches_fields {
+    use super::*;
+    use galvanic_assert::{Matcher, MatchResult};
+
+    struct Foo { x: i32, y: f64 }
+    struct Bar { foo: Foo, z: i32 }
+
+    #[test]
+    fn should_report_full_field_path_of_nested_structure() {
+        let bar = Bar { foo: Foo { x: 1, y: 2.0 }, z: 3 };
+        let matcher: Box<Matcher<Bar>> = matches_fields!(Bar {
+            foo: has_structure!(Foo { x: eq(2) }) => fatal,
+            z: eq(3)
+        });
+        match matcher.check(&bar) {
+            MatchResult::Failed { reason, .. } => {
+                assert!(reason.contains("field 'foo.x'"));
+                assert!(reason.contains("field 'foo' is fatal"));
+            },
+            MatchResult::Matched { .. } => panic!("matcher should fail")
+        }
+    }
+
+    fn not_evaluated() -> Box<Matcher<f64>> {
+        Box::new(|_: &f64| -> MatchResult { panic!("matcher should not be evaluated") })
+    }
+
+    #[test]
+    fn should_match() {
+        let foo = Foo { x: 12, y: 23.4 };
+        assert_that!(&foo, matches_fields!(Foo {
+            x: eq(12) => fatal,
+            y: lt(25.0) => collect
+        }));
+    }
+
+    #[test]
+    fn should_collect_failures_by_default() {
+        let foo = Foo { x: 12, y: 23.4 };
+        let matcher: Box<Matcher<Foo>> = matches_fields!(Foo {
+            x: eq(13),
+            y: gt(25.0)
+        });
+        match matcher.check(&foo) {
+            MatchResult::Failed { reason, .. } => {
+                assert!(reason.contains("field 'x'"));
+                assert!(reason.contains("field 'y'"));
+            },
+            MatchResult::Matched { .. } => panic!("matcher should fail")
+        }
+    }
+
+    #[test]
+    fn should_stop_after_fatal_failure() {
+        let foo = Foo { x: 12, y: 23.4 };
+        let matcher: Box<Matcher<Foo>> = matches_fields!(Foo {
+            x: eq(13) => fatal,
+            y: not_evaluated()
+        });
+        match matcher.check(&foo) {
+            MatchResult::Failed { reason, .. } => {
+                assert!(reason.contains("field 'x'"));
+                assert!(!reason.contains("field 'y'"));
+            },
+            MatchResult::Matched { .. } => panic!("matcher should fail")
+        }
+    }
+
+    #[test]
+    fn should_continue_after_passed_fatal_field() {
+        let foo = Foo { x: 12, y: 23.4 };
+        assert_that!(
+            assert_that!(&foo, matches_fields!(Foo {
+                x: eq(12) => fatal,
+                y: gt(25.0)
+            })),
+            panics
+        );
+    }
+}
+
+mod matched_if_ok {
+    use super::*;
+    use galvanic_assert::{Matcher, MatchResultBuilder};
+
+    fn parses_as_number<'a>() -> Box<Matcher<&'a str> + 'a> {
+        Box::new(|actual: &&str| MatchResultBuilder::for_("parses_as_number").matched_if_ok(actual.parse::<i32>()))
+    }
+
+    fn parses_as_bool<'a>() -> Box<Matcher<&'a str> + 'a> {
+        Box::new(|actual: &&str| MatchResultBuilder::for_("parses_as_bool").matched_if_ok(actual.parse::<bool>()))
+    }
+
+    #[test]
+    fn should_match() {
+        assert_that!(&"42", parses_as_number());
+        assert_that!(&"true", parses_as_bool());
+    }
+
+    #[test]
+    fn should_distinguish_failures_of_result_based_matchers() {
+        assert_that!(
+            assert_that!(&"maybe", parses_as_number()),
+            panics as payload => {
+                let message = galvanic_assert::panic_message(&*payload).unwrap();
+                assert_that!(message.contains("matcher: parses_as_number"));
+                assert_that!(message.contains("expected Ok(i32) but got Err("));
+            }
+        );
+        assert_that!(
+            assert_that!(&"maybe", parses_as_bool()),
+            panics as payload => {
+                let message = galvanic_assert::panic_message(&*payload).unwrap();
+                assert_that!(message.contains("matcher: parses_as_bool"));
+                assert_that!(message.contains("expected Ok(bool) but got Err("));
+            }
+        );
+    }
+}
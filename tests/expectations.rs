@@ -28,6 +28,21 @@ mod get_expectation_for {
             let e = get_expectation_for!(1 == 1);
         }
 
+        #[test]
+        fn should_get_an_expectation_asserting_an_expression_with_captures_to_be_true() {
+            let (a, b) = (1, 2);
+            #[allow(unused_variables)]
+            let e = get_expectation_for!(a, b => a < b);
+        }
+
+        #[test]
+        #[should_panic]
+        fn should_get_an_expectation_failing_to_assert_an_expression_with_captures_to_be_true() {
+            let (a, b) = (3, 2);
+            #[allow(unused_variables)]
+            let e = get_expectation_for!(a, b => a < b);
+        }
+
         #[test]
         fn should_get_an_expectation_asserting_an_expression_to_be_true_and_verify_manually() {
             let e = get_expectation_for!(1 == 1);
@@ -103,6 +118,24 @@ mod get_expectation_for {
         }
     }
 
+    mod use_deterministic {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        #[test]
+        fn should_get_an_expectation_asserting_an_expression_to_be_deterministic() {
+            #[allow(unused_variables)]
+            let e = get_expectation_for!(1 + 1, is deterministic(3));
+        }
+
+        #[test]
+        #[should_panic]
+        fn should_get_an_expectation_failing_to_assert_an_expression_to_be_deterministic() {
+            static COUNTER: AtomicUsize = AtomicUsize::new(0);
+            #[allow(unused_variables)]
+            let e = get_expectation_for!(COUNTER.fetch_add(1, Ordering::SeqCst), is deterministic(3));
+        }
+    }
+
     mod use_matcher {
         use super::*;
 
@@ -130,6 +163,19 @@ mod expect_that {
             expect_that!(1 == 1);
         }
 
+        #[test]
+        fn should_expect_an_expression_with_captures_to_be_true() {
+            let (a, b) = (1, 2);
+            expect_that!(a, b => a < b);
+        }
+
+        #[test]
+        #[should_panic]
+        fn should_be_failing_to_expect_an_expression_with_captures_to_be_true() {
+            let (a, b) = (3, 2);
+            expect_that!(a, b => a < b);
+        }
+
         #[test]
         fn should_get_multiple_expectations_asserting_an_expression_to_be_true() {
             expect_that!(1 == 1);
@@ -184,6 +230,22 @@ mod expect_that {
         }
     }
 
+    mod use_deterministic {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        #[test]
+        fn should_expect_an_expression_to_be_deterministic() {
+            expect_that!(1 + 1, is deterministic(3));
+        }
+
+        #[test]
+        #[should_panic]
+        fn should_be_failing_to_expect_an_expression_to_be_deterministic() {
+            static COUNTER: AtomicUsize = AtomicUsize::new(0);
+            expect_that!(COUNTER.fetch_add(1, Ordering::SeqCst), is deterministic(3));
+        }
+    }
+
     mod use_matcher {
         use super::*;
 
@@ -46,3 +46,253 @@ mod content {
         assert_that!(&file_path, content_as_bytes(eq(file_content.as_bytes().to_vec())))
     }
 }
+
+mod has_extension {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn should_match_extension() {
+        assert_that!(&PathBuf::from("dir/file.txt"), has_extension("txt"));
+        assert_that!(&PathBuf::from("dir/file.txt"), has_extension(".txt"));
+    }
+
+    #[test]
+    fn should_fail_due_to_different_extension() {
+        assert_that!(
+            assert_that!(&PathBuf::from("dir/file.txt"), has_extension("rs")),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_due_to_different_case() {
+        assert_that!(
+            assert_that!(&PathBuf::from("dir/file.TXT"), has_extension("txt")),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_due_to_missing_extension() {
+        assert_that!(
+            assert_that!(&PathBuf::from("dir/file"), has_extension("txt")),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_match_extension_ignoring_case() {
+        assert_that!(&PathBuf::from("dir/file.TXT"), has_extension_ignoring_case("txt"));
+    }
+}
+
+mod has_file_name {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn should_match_file_name() {
+        assert_that!(&PathBuf::from("dir/sub/file.txt"), has_file_name("file.txt"));
+        assert_that!(&"file.txt", has_file_name("file.txt"));
+    }
+
+    #[test]
+    fn should_fail_due_to_different_file_name() {
+        assert_that!(
+            assert_that!(&PathBuf::from("dir/file.txt"), has_file_name("dir")),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_due_to_missing_file_name() {
+        assert_that!(
+            assert_that!(&PathBuf::from("dir/.."), has_file_name("dir")),
+            panics
+        );
+    }
+}
+
+mod has_parent {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn should_match_parent() {
+        assert_that!(&PathBuf::from("out/reports/summary.txt"), has_parent(PathBuf::from("out/reports")));
+        assert_that!(&PathBuf::from("out/./reports/summary.txt"), has_parent(PathBuf::from("out/tmp/../reports/")));
+        assert_that!(&"summary.txt", has_parent(PathBuf::from("")));
+    }
+
+    #[test]
+    fn should_fail_due_to_different_parent() {
+        assert_that!(
+            assert_that!(&PathBuf::from("out/reports/summary.txt"), has_parent(PathBuf::from("out"))),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_due_to_missing_parent() {
+        assert_that!(
+            assert_that!(&PathBuf::from("/"), has_parent(PathBuf::from("/"))),
+            panics
+        );
+    }
+}
+
+mod is_inside {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn should_match_descendant() {
+        assert_that!(&PathBuf::from("/sandbox/dir/file.txt"), is_inside(PathBuf::from("/sandbox")));
+        assert_that!(&PathBuf::from("/sandbox/./dir/../file.txt"), is_inside(PathBuf::from("/sandbox")));
+    }
+
+    #[test]
+    fn should_match_base_itself() {
+        assert_that!(&PathBuf::from("/sandbox/dir/.."), is_inside(PathBuf::from("/sandbox")));
+    }
+
+    #[test]
+    fn should_fail_due_to_escaping_parent_dir() {
+        assert_that!(
+            assert_that!(&PathBuf::from("/sandbox/dir/../../etc/passwd"), is_inside(PathBuf::from("/sandbox"))),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_due_to_relative_escape() {
+        assert_that!(
+            assert_that!(&PathBuf::from("sandbox/../../file"), is_inside(PathBuf::from("sandbox"))),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_match_relative_path_inside_of_empty_base() {
+        assert_that!(&PathBuf::from("dir/file"), is_inside(PathBuf::from(".")));
+        assert_that!(&PathBuf::from("dir/file"), is_inside(PathBuf::from("a/..")));
+    }
+
+    #[test]
+    fn should_fail_due_to_escape_from_empty_base() {
+        for base in &[".", "a/.."] {
+            assert_that!(
+                assert_that!(&PathBuf::from("../etc/passwd"), is_inside(PathBuf::from(base))),
+                panics
+            );
+            assert_that!(
+                assert_that!(&PathBuf::from("/etc/passwd"), is_inside(PathBuf::from(base))),
+                panics
+            );
+        }
+    }
+
+    #[test]
+    fn should_match_inside_of_parent_base() {
+        assert_that!(&PathBuf::from("../sandbox/file"), is_inside(PathBuf::from("..")));
+    }
+
+    #[test]
+    fn should_fail_due_to_escape_from_parent_base() {
+        assert_that!(
+            assert_that!(&PathBuf::from("../../etc/passwd"), is_inside(PathBuf::from(".."))),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_due_to_sibling_with_common_prefix() {
+        assert_that!(
+            assert_that!(&PathBuf::from("/sandbox2/file"), is_inside(PathBuf::from("/sandbox"))),
+            panics
+        );
+    }
+}
+
+mod has_directory_tree {
+    use super::*;
+    use std::fs::create_dir_all;
+
+    fn create_tree() -> tempfile::TempDir {
+        let dir = tempdir().unwrap();
+        create_dir_all(dir.path().join("src/deep/deeper")).unwrap();
+        File::create(dir.path().join("Cargo.toml")).unwrap();
+        File::create(dir.path().join("src/lib.rs")).unwrap();
+        File::create(dir.path().join("src/deep/deeper/mod.rs")).unwrap();
+        dir
+    }
+
+    #[test]
+    fn should_match_whole_tree() {
+        let dir = create_tree();
+        assert_that!(&dir.path(), has_directory_tree(vec![
+            "Cargo.toml", "src", "src/lib.rs", "src/deep", "src/deep/deeper", "src/deep/deeper/mod.rs"
+        ], None));
+    }
+
+    #[test]
+    fn should_match_up_to_depth() {
+        let dir = create_tree();
+        assert_that!(&dir.path(), has_directory_tree(vec!["Cargo.toml", "src"], Some(1)));
+        assert_that!(&dir.path(), has_directory_tree(vec!["Cargo.toml", "src", "src/lib.rs", "src/deep"], Some(2)));
+    }
+
+    #[test]
+    fn should_fail_due_to_missing_entry() {
+        let dir = create_tree();
+        assert_that!(
+            assert_that!(&dir.path(), has_directory_tree(vec!["Cargo.toml", "src", "README.md"], Some(1))),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_due_to_unexpected_entry() {
+        let dir = create_tree();
+        assert_that!(
+            assert_that!(&dir.path(), has_directory_tree(vec!["src"], Some(1))),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_report_truncation() {
+        let dir = create_tree();
+        assert_that!(
+            assert_that!(&dir.path(), has_directory_tree(vec!["src"], Some(1))),
+            panics as payload => {
+                let message = galvanic_assert::panic_message(&*payload).unwrap();
+                assert_that!(message.contains("below level 1 were not compared"));
+            }
+        );
+    }
+
+    #[test]
+    fn should_fail_for_missing_directory() {
+        let dir = tempdir().unwrap();
+        assert_that!(
+            assert_that!(&dir.path().join("missing"), has_directory_tree(vec![], None)),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_report_missing_root() {
+        let dir = tempdir().unwrap();
+        let missing = dir.path().join("missing");
+        assert_that!(
+            assert_that!(&missing, has_directory_tree(vec![], None)),
+            panics as payload => {
+                let message = galvanic_assert::panic_message(&*payload).unwrap();
+                assert_that!(message.contains("matcher: has_directory_tree"));
+                assert_that!(message.contains(&format!("cannot read {:?}", missing)));
+            }
+        );
+    }
+}
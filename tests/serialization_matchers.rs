@@ -0,0 +1,96 @@
+/* Copyright 2017 Christopher Bacher
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#![cfg(feature = "serde")]
+
+#[macro_use]
+extern crate galvanic_assert;
+extern crate serde;
+
+use galvanic_assert::matchers::serialization::*;
+
+mod serialized_equal_to {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![(1, "one".to_owned())], serialized_equal_to(vec![(1, "one".to_owned())]));
+    }
+
+    #[test]
+    fn should_match_independent_of_map_order() {
+        let actual: HashMap<_,_> = (0..20).map(|i| (i.to_string(), i)).collect();
+        let expected: HashMap<_,_> = (0..20).rev().map(|i| (i.to_string(), i)).collect();
+        assert_that!(&actual, serialized_equal_to(expected));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&vec![1, 2], serialized_equal_to(vec![2, 1])),
+            panics
+        );
+    }
+}
+
+mod round_trips_via_json {
+    use super::*;
+    use galvanic_assert::MatchResult;
+    use serde::{Serialize, Serializer, Deserialize, Deserializer};
+    use std::collections::BTreeMap;
+
+    #[derive(Debug, PartialEq)]
+    struct Lossy { kept: i32, dropped: i32 }
+
+    impl Serialize for Lossy {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.kept.serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Lossy {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            i32::deserialize(deserializer).map(|kept| Lossy { kept: kept, dropped: 0 })
+        }
+    }
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![(1, "one".to_owned())], round_trips_via_json());
+        let map: BTreeMap<String, Option<i32>> = vec![("a".to_owned(), Some(1)), ("b".to_owned(), None)].into_iter().collect();
+        assert_that!(&map, round_trips_via_json());
+    }
+
+    #[test]
+    fn should_fail_if_deserialization_fails() {
+        // NaN is serialized as `null` which cannot be deserialized into a float
+        match round_trips_via_json().check(&vec![::std::f64::NAN]) {
+            MatchResult::Matched { .. } => panic!("matcher should fail"),
+            MatchResult::Failed { reason, .. } => assert_that!(reason.contains("failed to deserialize [null]"))
+        }
+    }
+
+    #[test]
+    fn should_fail_if_value_changes() {
+        match round_trips_via_json().check(&Lossy { kept: 1, dropped: 2 }) {
+            MatchResult::Matched { .. } => panic!("matcher should fail"),
+            MatchResult::Failed { reason, .. } => {
+                assert_that!(reason.contains("the value changed during the round trip via 1"));
+                assert_that!(reason.contains("Got: Lossy { kept: 1, dropped: 0 }"));
+            }
+        }
+    }
+}
@@ -0,0 +1,424 @@
+/* Copyright 2017 Christopher Bacher
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+use galvanic_assert::matchers::string::*;
+
+mod as_str {
+    use super::*;
+    use std::borrow::Cow;
+
+    #[test]
+    fn should_match_cow() {
+        let cow: Cow<str> = Cow::Owned("foo".to_owned());
+        assert_that!(&cow, as_str(eq("foo".to_owned())));
+    }
+
+    #[test]
+    fn should_match_boxed_str() {
+        let boxed: Box<str> = "foo".into();
+        assert_that!(&boxed, as_str(eq("foo".to_owned())));
+    }
+
+    #[test]
+    fn should_match_str_slice() {
+        assert_that!(&"foo", as_str(eq("foo".to_owned())));
+    }
+
+    #[test]
+    fn should_fail() {
+        let cow: Cow<str> = Cow::Borrowed("foo");
+        assert_that!(
+            assert_that!(&cow, as_str(eq("bar".to_owned()))),
+            panics
+        );
+    }
+}
+
+mod one_of_ignoring_case {
+    use super::*;
+
+    fn candidates() -> Vec<String> {
+        vec!["true".to_owned(), "yes".to_owned(), "on".to_owned()]
+    }
+
+    #[test]
+    fn should_match() {
+        assert_that!(&"yes", one_of_ignoring_case(candidates()));
+        assert_that!(&"TRUE".to_owned(), one_of_ignoring_case(candidates()));
+        assert_that!(&"On", one_of_ignoring_case(candidates()));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&"no", one_of_ignoring_case(candidates())),
+            panics
+        );
+    }
+}
+
+mod has_word_count {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&"one two\tthree\n four ", has_word_count(4));
+        assert_that!(&"", has_word_count(0));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&"one two", has_word_count(3)),
+            panics
+        );
+    }
+}
+
+mod has_line_count {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&"one\ntwo\nthree", has_line_count(3));
+        assert_that!(&"one\r\ntwo\n", has_line_count(2));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&"one\ntwo", has_line_count(3)),
+            panics
+        );
+    }
+}
+
+mod every_line_starts_with {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&"> quoted\n> reply\n", every_line_starts_with("> "));
+        assert_that!(&"    indented\r\n    block".to_owned(), every_line_starts_with("    "));
+        assert_that!(&"", every_line_starts_with("#"));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&"# one\ntwo\n# three", every_line_starts_with("# ")),
+            panics
+        );
+    }
+}
+
+mod is_trimmed {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&"foo  bar", is_trimmed());
+        assert_that!(&"", is_trimmed());
+    }
+
+    #[test]
+    fn should_fail_due_to_leading_whitespace() {
+        assert_that!(
+            assert_that!(&"\tfoo", is_trimmed()),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_due_to_trailing_whitespace() {
+        assert_that!(
+            assert_that!(&"foo\n".to_owned(), is_trimmed()),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_for_whitespace_only() {
+        assert_that!(
+            assert_that!(&"  ", is_trimmed()),
+            panics
+        );
+    }
+}
+
+mod displays_with_length {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&12345, displays_with_length(eq(5)));
+        assert_that!(&"größe", displays_with_length(eq(5)));
+        assert_that!(&1.5, displays_with_length(leq(3)));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&"too wide for the column", displays_with_length(leq(10))),
+            panics
+        );
+    }
+}
+
+mod contains_all_substrings {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&"user=foo id=42 status=ok", contains_all_substrings(vec!["status=ok", "user=foo"]));
+        assert_that!(&"foo".to_owned(), contains_all_substrings(vec![]));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&"user=foo id=42", contains_all_substrings(vec!["id=42", "status=ok", "user=bar"])),
+            panics
+        );
+    }
+}
+
+mod contains_substrings_in_order {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&"START tx=1 COMMIT END", contains_substrings_in_order(vec!["START", "COMMIT", "END"]));
+        assert_that!(&"abab", contains_substrings_in_order(vec!["ab", "ab"]));
+    }
+
+    #[test]
+    fn should_fail_due_to_wrong_order() {
+        assert_that!(
+            assert_that!(&"START END COMMIT", contains_substrings_in_order(vec!["START", "COMMIT", "END"])),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_due_to_missing_repetition() {
+        assert_that!(
+            assert_that!(&"aba", contains_substrings_in_order(vec!["ab", "ab"])),
+            panics
+        );
+    }
+}
+
+mod split_by {
+    use super::*;
+    use galvanic_assert::{Matcher, MatchResult};
+
+    #[test]
+    fn should_match() {
+        assert_that!(&"42;alice;admin", split_by(";", vec![looks_numeric(), eq("alice".to_owned()), any_value()]));
+        assert_that!(&"usr/local/bin".to_owned(), split_by("/", vec![any_value(), eq("local".to_owned()), any_value()]));
+    }
+
+    #[test]
+    fn should_fail_due_to_field_count() {
+        assert_that!(
+            assert_that!(&"42;alice", split_by(";", vec![looks_numeric(), any_value(), any_value()])),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_due_to_field() {
+        assert_that!(
+            assert_that!(&"x;alice", split_by(";", vec![looks_numeric(), eq("alice".to_owned())])),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_report_failed_field() {
+        match split_by(";", vec![any_value(), eq("bob".to_owned())]).check(&"42;alice") {
+            MatchResult::Failed { reason, .. } => {
+                assert_that!(reason.contains("field 1 \"alice\" failed to match equal"));
+            },
+            MatchResult::Matched { .. } => panic!("should have failed")
+        }
+    }
+}
+
+mod error_displays {
+    use super::*;
+    use std::io;
+
+    #[test]
+    fn should_match() {
+        let error = io::Error::new(io::ErrorKind::NotFound, "config file missing");
+        assert_that!(&error, error_displays(eq("config file missing".to_owned())));
+        assert_that!(&error, error_displays(contains_all_substrings(vec!["missing"])));
+    }
+
+    #[test]
+    fn should_fail() {
+        let error = "x".parse::<i32>().unwrap_err();
+        assert_that!(
+            assert_that!(&error, error_displays(eq("InvalidDigit".to_owned()))),
+            panics
+        );
+    }
+}
+
+mod looks_numeric {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        for literal in &["0", "42", "+7", "-3.14", ".5", "5.", "1e10", "-2.5E-3", "6e+2"] {
+            assert_that!(literal, looks_numeric());
+        }
+    }
+
+    #[test]
+    fn should_fail() {
+        for literal in &["", "-", ".", "12,5", "1e", "1e+", "e5", " 1", "1 ", "0x10", "1.2.3"] {
+            assert_that!(
+                assert_that!(literal, looks_numeric()),
+                panics
+            );
+        }
+    }
+}
+
+mod is_valid_identifier {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        for identifier in &["x", "_", "_user_id2", "CamelCase", "snake_case_42"] {
+            assert_that!(identifier, is_valid_identifier());
+        }
+    }
+
+    #[test]
+    fn should_fail() {
+        for identifier in &["", "2fast", "user-id", "white space", "ünicode", "trailing!"] {
+            assert_that!(
+                assert_that!(identifier, is_valid_identifier()),
+                panics
+            );
+        }
+    }
+}
+
+mod is_printable_ascii {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&b"log line\twith tab\r\n".to_vec(), is_printable_ascii());
+        assert_that!(&"~ !".to_owned(), is_printable_ascii());
+        assert_that!(&Vec::<u8>::new(), is_printable_ascii());
+    }
+
+    #[test]
+    fn should_fail_due_to_control_character() {
+        assert_that!(
+            assert_that!(&b"esc\x1b[0m".to_vec(), is_printable_ascii()),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_due_to_non_ascii() {
+        assert_that!(
+            assert_that!(&"grüße".to_owned(), is_printable_ascii()),
+            panics
+        );
+    }
+}
+
+mod has_byte_length {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&"größe", has_byte_length(7));
+        assert_that!(&"".to_owned(), has_byte_length(0));
+    }
+
+    #[test]
+    fn should_fail_for_char_length() {
+        assert_that!(
+            assert_that!(&"größe", has_byte_length(5)),
+            panics
+        );
+    }
+}
+
+mod has_char_length {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&"größe", has_char_length(5));
+        assert_that!(&"".to_owned(), has_char_length(0));
+    }
+
+    #[test]
+    fn should_fail_for_byte_length() {
+        assert_that!(
+            assert_that!(&"größe", has_char_length(7)),
+            panics
+        );
+    }
+}
+
+mod matches_glob {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&"report-2017.csv", matches_glob("report-[0-9][0-9][0-9][0-9].*"));
+        assert_that!(&"", matches_glob("*"));
+        assert_that!(&"abc", matches_glob("a?c"));
+        assert_that!(&"abc", matches_glob("*b*"));
+        assert_that!(&"a*c", matches_glob("a[*]c"));
+        assert_that!(&"x-y", matches_glob("x[a-]y"));
+        assert_that!(&"file.rs", matches_glob("*.[!c]s"));
+        assert_that!(&"mississippi", matches_glob("m*iss*ppi"));
+    }
+
+    #[test]
+    fn should_fail() {
+        for &(text, pattern) in &[("report.txt", "*.csv"), ("abc", "a?"), ("abc", "[!a]*"),
+                                  ("abd", "ab[c-]"), ("mississippi", "m*iss*pa")] {
+            assert_that!(
+                assert_that!(&text, matches_glob(pattern)),
+                panics
+            );
+        }
+    }
+
+    #[test]
+    fn should_fail_for_invalid_pattern() {
+        assert_that!(
+            assert_that!(&"[abc", matches_glob("[abc")),
+            panics
+        );
+    }
+}
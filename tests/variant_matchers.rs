@@ -40,6 +40,129 @@ mod is_variant {
     }
 }
 
+mod is_variant_dbg {
+    use super::*;
+    use galvanic_assert::{Matcher, MatchResult};
+
+    #[test]
+    fn should_succeed() {
+        assert_that!(&MyEnum::Var2 { x: 1 }, is_variant_dbg!(MyEnum::Var2));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&MyEnum::Var1, is_variant_dbg!(MyEnum::Var2)),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_report_actual_value() {
+        let matcher: Box<Matcher<MyEnum>> = is_variant_dbg!(MyEnum::Var1);
+        match matcher.check(&MyEnum::Var2 { x: 3 }) {
+            MatchResult::Failed { reason, .. } => assert!(reason.contains("Var2 { x: 3 }")),
+            MatchResult::Matched { .. } => panic!("matcher should fail")
+        }
+    }
+}
+
+mod err_is_variant {
+    use super::*;
+    use galvanic_assert::{Matcher, MatchResult};
+
+    #[test]
+    fn should_succeed() {
+        let result: Result<i32, MyEnum> = Err(MyEnum::Var2 { x: 1 });
+        assert_that!(&result, err_is_variant!(MyEnum::Var2));
+    }
+
+    #[test]
+    fn should_fail_because_of_other_variant() {
+        let result: Result<i32, MyEnum> = Err(MyEnum::Var1);
+        assert_that!(
+            assert_that!(&result, err_is_variant!(MyEnum::Var2)),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_because_of_ok() {
+        let result: Result<i32, MyEnum> = Ok(1);
+        assert_that!(
+            assert_that!(&result, err_is_variant!(MyEnum::Var1)),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_report_actual_variant() {
+        let matcher: Box<Matcher<Result<i32, MyEnum>>> = err_is_variant!(MyEnum::Var1);
+        match matcher.check(&Err(MyEnum::Var2 { x: 3 })) {
+            MatchResult::Failed { reason, .. } => assert!(reason.contains("Var2 { x: 3 }")),
+            MatchResult::Matched { .. } => panic!("matcher should fail")
+        }
+    }
+}
+
+mod variant_in {
+    use super::*;
+    use galvanic_assert::matchers::variant::variant_in;
+
+    #[test]
+    fn should_succeed() {
+        assert_that!(&MyEnum::Var2 { x: 1 }, variant_in(&[MyEnum::Var1, MyEnum::Var2 { x: 0 }]));
+        assert_that!(&MyEnum::Var1, variant_in(&[MyEnum::Var1]));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&MyEnum::Var1, variant_in(&[MyEnum::Var2 { x: 0 }])),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_for_no_samples() {
+        assert_that!(
+            assert_that!(&MyEnum::Var1, variant_in(&[])),
+            panics
+        );
+    }
+}
+
+mod variants {
+    use super::*;
+    use galvanic_assert::{Matcher, MatchResult};
+
+    #[test]
+    fn should_succeed() {
+        assert_that!(&MyEnum::Var2 { x: 1 }, variants!(MyEnum::Var1, MyEnum::Var2 { x: 0 }));
+        assert_that!(&MyEnum::Var1, variants!(MyEnum::Var1));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&MyEnum::Var1, variants!(MyEnum::Var2 { x: 0 })),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_report_accepted_variants() {
+        let matcher = variants!(Some(0), Some(1));
+        match matcher.check(&None) {
+            MatchResult::Failed { reason, .. } => {
+                assert_that!(reason.contains("None"));
+                assert_that!(reason.contains("'Some(0), Some(1)'"));
+            },
+            MatchResult::Matched { .. } => panic!("should have failed")
+        }
+    }
+}
+
 mod maybe_some {
     use galvanic_assert::matchers::equal_to;
     use galvanic_assert::matchers::variant::maybe_some;
@@ -65,6 +188,66 @@ mod maybe_some {
     }
 }
 
+mod some_contained_in {
+    use galvanic_assert::matchers::variant::some_contained_in;
+
+    #[test]
+    fn should_succeed() {
+        assert_that!(&Some("red"), some_contained_in(vec!["red", "green", "blue"]));
+    }
+
+    #[test]
+    fn should_fail_because_of_none_value() {
+        assert_that!(
+            assert_that!(&None, some_contained_in(vec!["red", "green", "blue"])),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_because_of_value_not_contained() {
+        assert_that!(
+            assert_that!(&Some("yellow"), some_contained_in(vec!["red", "green", "blue"])),
+            panics
+        );
+    }
+}
+
+mod flattened_some {
+    use galvanic_assert::{Matcher, MatchResult};
+    use galvanic_assert::matchers::equal_to;
+    use galvanic_assert::matchers::variant::flattened_some;
+
+    #[test]
+    fn should_succeed() {
+        assert_that!(&Some(Some(2)), flattened_some(equal_to(2)));
+    }
+
+    #[test]
+    fn should_fail_because_of_outer_none_value() {
+        let maybe_int: Option<Option<i32>> = None;
+        match flattened_some(equal_to(2)).check(&maybe_int) {
+            MatchResult::Failed { reason, .. } => assert!(reason.contains("outer")),
+            MatchResult::Matched { .. } => panic!("matcher should fail")
+        }
+    }
+
+    #[test]
+    fn should_fail_because_of_inner_none_value() {
+        let maybe_int: Option<Option<i32>> = Some(None);
+        match flattened_some(equal_to(2)).check(&maybe_int) {
+            MatchResult::Failed { reason, .. } => assert!(reason.contains("inner")),
+            MatchResult::Matched { .. } => panic!("matcher should fail")
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_fail_because_nested_matcher_fails() {
+        assert_that!(&Some(Some(3)), flattened_some(equal_to(2)));
+    }
+}
+
 mod maybe_ok {
     use galvanic_assert::matchers::equal_to;
     use galvanic_assert::matchers::variant::maybe_ok;
@@ -90,6 +273,71 @@ mod maybe_ok {
     }
 }
 
+mod ok_matching {
+    use galvanic_assert::{Matcher, MatchResult};
+    use galvanic_assert::matchers::equal_to;
+    use galvanic_assert::matchers::variant::ok_matching;
+
+    #[test]
+    fn should_succeed() {
+        let maybe_int: Result<i32, String> = Ok(2);
+        assert_that!(&maybe_int, ok_matching(equal_to(2)));
+    }
+
+    #[test]
+    fn should_fail_with_error_in_message() {
+        let maybe_int: Result<i32, String> = Err("Failed".to_owned());
+        match ok_matching(equal_to(2)).check(&maybe_int) {
+            MatchResult::Failed { reason, .. } => assert!(reason.contains("Err(\"Failed\")")),
+            MatchResult::Matched { .. } => panic!("matcher should fail")
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_fail_because_nested_matcher_fails() {
+        let maybe_int: Result<i32, String> = Ok(3);
+        assert_that!(&maybe_int, ok_matching(equal_to(2)));
+    }
+}
+
+mod ok_some {
+    use galvanic_assert::MatchResult;
+    use galvanic_assert::matchers::equal_to;
+    use galvanic_assert::matchers::variant::ok_some;
+
+    #[test]
+    fn should_succeed() {
+        let lookup: Result<Option<i32>, String> = Ok(Some(2));
+        assert_that!(&lookup, ok_some(equal_to(2)));
+    }
+
+    #[test]
+    fn should_fail_with_error_in_message() {
+        let lookup: Result<Option<i32>, String> = Err("Failed".to_owned());
+        match ok_some(equal_to(2)).check(&lookup) {
+            MatchResult::Failed { reason, .. } => assert!(reason.contains("Err(\"Failed\")")),
+            MatchResult::Matched { .. } => panic!("matcher should fail")
+        }
+    }
+
+    #[test]
+    fn should_fail_with_none_in_message() {
+        let lookup: Result<Option<i32>, String> = Ok(None);
+        match ok_some(equal_to(2)).check(&lookup) {
+            MatchResult::Failed { reason, .. } => assert!(reason.contains("Ok but contains None")),
+            MatchResult::Matched { .. } => panic!("matcher should fail")
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_fail_because_nested_matcher_fails() {
+        let lookup: Result<Option<i32>, String> = Ok(Some(3));
+        assert_that!(&lookup, ok_some(equal_to(2)));
+    }
+}
+
 mod maybe_err {
     use galvanic_assert::matchers::equal_to;
     use galvanic_assert::matchers::variant::maybe_err;
@@ -114,3 +362,43 @@ mod maybe_err {
         assert_that!(&maybe_int, maybe_err(equal_to(2)));
     }
 }
+
+mod pipeline_succeeds {
+    use galvanic_assert::{Matcher, MatchResult};
+    use galvanic_assert::matchers::variant::pipeline_succeeds;
+
+    fn parse(s: String) -> Result<String, String> {
+        s.parse::<i32>().map(|_| s.clone()).map_err(|err| err.to_string())
+    }
+
+    #[test]
+    fn should_succeed() {
+        assert_that!(&" 42 ".to_owned(), pipeline_succeeds(vec![
+            ("trim", Box::new(|s: String| Ok(s.trim().to_owned()))),
+            ("parse", Box::new(parse)),
+        ]));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&"4 2".to_owned(), pipeline_succeeds(vec![
+                ("trim", Box::new(|s: String| Ok(s.trim().to_owned()))),
+                ("parse", Box::new(parse)),
+            ])),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_report_failing_step() {
+        let matcher: Box<Matcher<String>> = pipeline_succeeds(vec![
+            ("parse", Box::new(parse)),
+            ("unreachable", Box::new(|_: String| -> Result<String, String> { panic!("step should not run") })),
+        ]);
+        match matcher.check(&"x".to_owned()) {
+            MatchResult::Failed { reason, .. } => assert!(reason.contains("step 1 'parse'")),
+            MatchResult::Matched { .. } => panic!("matcher should fail")
+        }
+    }
+}